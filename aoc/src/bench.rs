@@ -0,0 +1,92 @@
+use common::bench::{DayBench, Stats};
+
+fn stages(result: &DayBench) -> impl Iterator<Item = (String, &Stats)> {
+    std::iter::once(("parse".to_owned(), &result.parse)).chain(
+        result
+            .parts
+            .iter()
+            .map(|(part, stats)| (format!("part{}", part.number()), stats)),
+    )
+}
+
+pub fn print_table(results: &[DayBench]) {
+    println!(
+        "{:>4}  {:>6}  {:>7}  {:>11}  {:>11}  {:>11}  {:>11}  {:>11}",
+        "day", "stage", "samples", "min", "median", "p90", "p99", "max"
+    );
+
+    for result in results {
+        for (stage, stats) in stages(result) {
+            println!(
+                "{:>4}  {:>6}  {:>7}  {:>11}  {:>11}  {:>11}  {:>11}  {:>11}",
+                result.day,
+                stage,
+                stats.samples,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.p90),
+                format!("{:.2?}", stats.p99),
+                format!("{:.2?}", stats.max),
+            );
+        }
+    }
+}
+
+/// One object per day and stage, with all timings in nanoseconds.
+pub fn to_json(results: &[DayBench], repeat: usize) -> String {
+    let entries: Vec<String> = results
+        .iter()
+        .flat_map(|result| {
+            stages(result).map(move |(stage, stats)| {
+                format!(
+                    "    {{\"day\": {}, \"stage\": \"{}\", \"samples\": {}, \"min_ns\": {}, \
+                     \"median_ns\": {}, \"p90_ns\": {}, \"p99_ns\": {}, \"max_ns\": {}, \
+                     \"mean_ns\": {}}}",
+                    result.day,
+                    stage,
+                    stats.samples,
+                    stats.min.as_nanos(),
+                    stats.median.as_nanos(),
+                    stats.p90.as_nanos(),
+                    stats.p99.as_nanos(),
+                    stats.max.as_nanos(),
+                    stats.mean.as_nanos(),
+                )
+            })
+        })
+        .collect();
+
+    format!(
+        "{{\n  \"repeat\": {},\n  \"results\": [\n{}\n  ]\n}}",
+        repeat,
+        entries.join(",\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Part;
+    use std::time::Duration;
+
+    #[test]
+    fn test_to_json() {
+        let stats = Stats::from_samples(vec![Duration::from_nanos(5), Duration::from_nanos(7)]);
+        let results = [DayBench {
+            day: 6,
+            parse: stats.clone(),
+            parts: vec![(Part::Two, stats)],
+        }];
+
+        assert_eq!(
+            to_json(&results, 2),
+            r#"{
+  "repeat": 2,
+  "results": [
+    {"day": 6, "stage": "parse", "samples": 2, "min_ns": 5, "median_ns": 5, "p90_ns": 7, "p99_ns": 7, "max_ns": 7, "mean_ns": 6},
+    {"day": 6, "stage": "part2", "samples": 2, "min_ns": 5, "median_ns": 5, "p90_ns": 7, "p99_ns": 7, "max_ns": 7, "mean_ns": 6}
+  ]
+}"#
+        );
+    }
+}
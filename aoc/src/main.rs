@@ -0,0 +1,310 @@
+mod bench;
+mod verify;
+
+use common::bench::DayBench;
+use common::{DayResult, InputSource, ParseError, Part, Solution};
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+use verify::{Answers, Verdict};
+
+struct Day {
+    day: u8,
+    run: fn(&str, &[Part]) -> Result<DayResult, ParseError>,
+    bench: fn(&str, &[Part], usize) -> Result<DayBench, ParseError>,
+}
+
+macro_rules! day {
+    ($krate:ident :: $solution:ident) => {
+        Day {
+            day: <$krate::$solution as Solution>::DAY,
+            run: common::run::<$krate::$solution>,
+            bench: common::bench::bench::<$krate::$solution>,
+        }
+    };
+}
+
+const DAYS: &[Day] = &[
+    day!(day1::Day1),
+    day!(day2::Day2),
+    day!(day3::Day3),
+    day!(day4::Day4),
+    day!(day5::Day5),
+    day!(day6::Day6),
+    day!(day7::Day7),
+    day!(day8::Day8),
+    day!(day9::Day9),
+    day!(day10::Day10),
+    day!(day11::Day11),
+    day!(day12::Day12),
+    day!(day13::Day13),
+    day!(day16::Day16),
+    day!(day17::Day17),
+    day!(day18::Day18),
+    day!(day20::Day20),
+];
+
+const USAGE: &str = "usage: aoc run <day|all> [--part <1|2>] [--input <path|->] \
+                     [--verify [--answers <path>]] [--bench [--repeat <n>] [--json]]";
+
+const DEFAULT_ANSWERS: &str = "answers.toml";
+const DEFAULT_REPEAT: usize = 10;
+
+struct Args {
+    days: Vec<&'static Day>,
+    parts: &'static [Part],
+    input: InputSource,
+    verify: bool,
+    answers: Option<PathBuf>,
+    bench: bool,
+    repeat: usize,
+    json: bool,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut args = args.iter();
+    match args.next().map(|s| s.as_str()) {
+        Some("run") => (),
+        Some(other) => return Err(format!("unknown command: {}", other)),
+        None => return Err("missing command".to_owned()),
+    }
+
+    let days = match args.next().map(|s| s.as_str()) {
+        Some("all") => DAYS.iter().collect(),
+        Some(day) => {
+            let day: u8 = day.parse().map_err(|_| format!("invalid day: {}", day))?;
+            let found = DAYS
+                .iter()
+                .find(|d| d.day == day)
+                .ok_or_else(|| format!("day {} is not solved", day))?;
+            vec![found]
+        }
+        None => return Err("missing day".to_owned()),
+    };
+
+    let mut parts = Part::ALL;
+    let mut input = InputSource::Default;
+    let mut verify = false;
+    let mut answers = None;
+    let mut bench = false;
+    let mut repeat = None;
+    let mut json = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                parts = match args.next().map(|s| s.as_str()) {
+                    Some("1") => &[Part::One],
+                    Some("2") => &[Part::Two],
+                    Some(other) => return Err(format!("invalid part: {}", other)),
+                    None => return Err("--part requires a value".to_owned()),
+                }
+            }
+            "--input" => {
+                input = match args.next().map(|s| s.as_str()) {
+                    Some("-") => InputSource::Stdin,
+                    Some(path) => InputSource::File(PathBuf::from(path)),
+                    None => return Err("--input requires a value".to_owned()),
+                }
+            }
+            "--verify" => verify = true,
+            "--answers" => {
+                let path = args.next().ok_or("--answers requires a value")?;
+                answers = Some(PathBuf::from(path));
+            }
+            "--bench" => bench = true,
+            "--repeat" => {
+                let count = args.next().ok_or("--repeat requires a value")?;
+                match count.parse() {
+                    Ok(count) if count > 0 => repeat = Some(count),
+                    _ => return Err(format!("invalid repeat count: {}", count)),
+                }
+            }
+            "--json" => json = true,
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+
+    if input != InputSource::Default && days.len() != 1 {
+        return Err("--input can only be used with a single day".to_owned());
+    }
+
+    if answers.is_some() && !verify {
+        return Err("--answers can only be used with --verify".to_owned());
+    }
+    if (repeat.is_some() || json) && !bench {
+        return Err("--repeat and --json can only be used with --bench".to_owned());
+    }
+    if bench && verify {
+        return Err("--bench and --verify cannot be combined".to_owned());
+    }
+
+    Ok(Args {
+        days,
+        parts,
+        input,
+        verify,
+        answers,
+        bench,
+        repeat: repeat.unwrap_or(DEFAULT_REPEAT),
+        json,
+    })
+}
+
+/// Print answers and timings, checking them against `answers` if given.
+/// Returns false if any answer was wrong or any part failed.
+fn print_table(results: &[DayResult], answers: Option<&Answers>) -> bool {
+    let check_width = if answers.is_some() { 9 } else { 0 };
+    let answer_column = 40 + check_width;
+    println!(
+        "{:>4}  {:>4}  {:>12}  {:>12}  {:<check_width$}answer",
+        "day",
+        "part",
+        "parse",
+        "solve",
+        if answers.is_some() { "check" } else { "" },
+    );
+
+    let mut total = Duration::ZERO;
+    let mut all_passed = true;
+    for result in results {
+        total += result.parse_elapsed;
+        for (idx, part) in result.parts.iter().enumerate() {
+            total += part.elapsed;
+            let parse = if idx == 0 {
+                format!("{:.2?}", result.parse_elapsed)
+            } else {
+                String::new()
+            };
+            let answer = part.answer.as_deref().map_err(String::as_str);
+            let verdict = answers.map(|answers| answers.check(result.day, part.part, answer));
+            let text = match answer {
+                Ok(answer) => answer.to_owned(),
+                Err(err) => {
+                    all_passed = false;
+                    format!("error: {}", err)
+                }
+            };
+
+            let mut lines = text.lines();
+            println!(
+                "{:>4}  {:>4}  {:>12}  {:>12}  {:<check_width$}{}",
+                result.day,
+                part.part.number(),
+                parse,
+                format!("{:.2?}", part.elapsed),
+                verdict.as_ref().map_or("", Verdict::label),
+                lines.next().unwrap_or_default()
+            );
+            for line in lines {
+                println!("{:>answer_column$}{}", "", line);
+            }
+
+            if let Some(Verdict::Fail { expected }) = &verdict {
+                all_passed = false;
+                let Some(expected) = expected else {
+                    continue;
+                };
+                let mut lines = expected.lines();
+                println!(
+                    "{:>answer_column$}expected: {}",
+                    "",
+                    lines.next().unwrap_or_default()
+                );
+                for line in lines {
+                    println!("{:>answer_column$}          {}", "", line);
+                }
+            }
+        }
+    }
+
+    println!("total: {:.2?}", total);
+
+    all_passed
+}
+
+fn load_answers(path: Option<&Path>) -> Result<Answers, String> {
+    let path = match path {
+        Some(path) => path.to_owned(),
+        None => common::find_upwards(Path::new(DEFAULT_ANSWERS))
+            .map_err(|err| err.to_string())?
+            .ok_or_else(|| format!("no {} found; pass one with --answers", DEFAULT_ANSWERS))?,
+    };
+
+    Answers::load(&path)
+}
+
+/// Load each requested day's input and hand it to `f`, reporting failures
+/// as they happen. Returns the successful results and whether any failed.
+fn run_days<T>(args: &Args, f: impl Fn(&Day, &str) -> Result<T, ParseError>) -> (Vec<T>, bool) {
+    let mut results = Vec::new();
+    let mut failed = false;
+    for day in &args.days {
+        let input = match common::load_input(day.day, &args.input) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                failed = true;
+                continue;
+            }
+        };
+
+        match f(day, &input) {
+            Ok(result) => results.push(result),
+            Err(err) => {
+                eprintln!("error: day {}: {}", day.day, err.diagnostic(&input));
+                failed = true;
+            }
+        }
+    }
+
+    (results, failed)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = match parse_args(&args) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    if args.bench {
+        let (results, failed) = run_days(&args, |day, input| {
+            (day.bench)(input, args.parts, args.repeat)
+        });
+        if args.json {
+            println!("{}", bench::to_json(&results, args.repeat));
+        } else {
+            bench::print_table(&results);
+        }
+
+        if failed {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let answers = if args.verify {
+        match load_answers(args.answers.as_deref()) {
+            Ok(answers) => Some(answers),
+            Err(err) => {
+                eprintln!("error: {}", err);
+                process::exit(1);
+            }
+        }
+    } else {
+        None
+    };
+
+    let (results, mut failed) = run_days(&args, |day, input| (day.run)(input, args.parts));
+    if !print_table(&results, answers.as_ref()) {
+        failed = true;
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
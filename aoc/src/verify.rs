@@ -0,0 +1,131 @@
+use common::Part;
+use std::fs;
+use std::path::Path;
+use toml::{Table, Value};
+
+/// Known-good answers, one table per day:
+///
+/// ```toml
+/// [day1]
+/// part1 = 1711
+/// part2 = "1743"
+/// ```
+pub struct Answers {
+    table: Table,
+}
+
+pub enum Verdict {
+    Pass,
+    /// A wrong answer, or a part that failed instead of answering.
+    Fail {
+        expected: Option<String>,
+    },
+    Missing,
+}
+
+impl Verdict {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail { .. } => "FAIL",
+            Verdict::Missing => "MISSING",
+        }
+    }
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("could not read {}: {}", path.display(), err))?;
+        let table = text
+            .parse()
+            .map_err(|err| format!("could not parse {}: {}", path.display(), err))?;
+
+        Ok(Self { table })
+    }
+
+    fn expected(&self, day: u8, part: Part) -> Option<String> {
+        let value = self
+            .table
+            .get(&format!("day{}", day))?
+            .get(format!("part{}", part.number()))?;
+
+        match value {
+            Value::String(s) => Some(s.clone()),
+            Value::Integer(i) => Some(i.to_string()),
+            other => Some(format!("{:?}", other)),
+        }
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: Result<&str, &str>) -> Verdict {
+        match (self.expected(day, part), answer) {
+            (Some(expected), Ok(answer)) if expected.trim_end() == answer.trim_end() => {
+                Verdict::Pass
+            }
+            (expected, Err(_)) => Verdict::Fail { expected },
+            (Some(expected), Ok(_)) => Verdict::Fail {
+                expected: Some(expected),
+            },
+            (None, Ok(_)) => Verdict::Missing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[day1]
+part1 = 7
+part2 = "5"
+
+[day13]
+part2 = """
+#.#
+.#.
+"""
+"#;
+
+    #[test]
+    fn test_check() {
+        let answers = Answers {
+            table: ANSWERS.parse().unwrap(),
+        };
+
+        assert!(matches!(
+            answers.check(1, Part::One, Ok("7")),
+            Verdict::Pass
+        ));
+        assert!(matches!(
+            answers.check(1, Part::Two, Ok("5")),
+            Verdict::Pass
+        ));
+        assert!(matches!(
+            answers.check(13, Part::Two, Ok("#.#\n.#.\n")),
+            Verdict::Pass
+        ));
+        assert!(matches!(
+            answers.check(1, Part::One, Ok("8")),
+            Verdict::Fail { expected: Some(_) }
+        ));
+        assert!(matches!(
+            answers.check(13, Part::One, Ok("17")),
+            Verdict::Missing
+        ));
+        assert!(matches!(
+            answers.check(2, Part::One, Ok("150")),
+            Verdict::Missing
+        ));
+
+        // A part that failed fails even with nothing to compare against.
+        assert!(matches!(
+            answers.check(1, Part::One, Err("overflow")),
+            Verdict::Fail { expected: Some(_) }
+        ));
+        assert!(matches!(
+            answers.check(2, Part::One, Err("overflow")),
+            Verdict::Fail { expected: None }
+        ));
+    }
+}
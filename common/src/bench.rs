@@ -0,0 +1,132 @@
+use crate::{ParseError, Part, Solution};
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Summary of repeated timings of one stage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// Summarise `samples`, which must not be empty.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        samples.sort_unstable();
+
+        let total: Duration = samples.iter().sum();
+        Self {
+            samples: samples.len(),
+            min: samples[0],
+            median: percentile(&samples, 50),
+            p90: percentile(&samples, 90),
+            p99: percentile(&samples, 99),
+            max: samples[samples.len() - 1],
+            mean: total / samples.len() as u32,
+        }
+    }
+}
+
+/// Median, minimum and maximum, each right-aligned for tables.
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "median {:>10.2?}  min {:>10.2?}  max {:>10.2?}",
+            self.median, self.min, self.max
+        )
+    }
+}
+
+/// Print one line of `name` followed by its stats.
+pub fn report(name: &str, stats: &Stats) {
+    println!("{:<12} {}", name, stats);
+}
+
+/// Nearest-rank percentile of sorted samples.
+fn percentile(sorted: &[Duration], pct: usize) -> Duration {
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+/// Time `f` `repeat` times after a single untimed warm-up run.
+pub fn measure<T>(repeat: usize, mut f: impl FnMut() -> T) -> Stats {
+    black_box(f());
+
+    let samples = (0..repeat.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(samples)
+}
+
+#[derive(Debug)]
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+/// Time parsing and each requested part separately, `repeat` times each.
+pub fn bench<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    repeat: usize,
+) -> Result<DayBench, ParseError> {
+    let parsed = S::parse(input)?;
+    let parse = measure(repeat, || S::parse(input));
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let stats = match part {
+                Part::One => measure(repeat, || S::solve1(&parsed)),
+                Part::Two => measure(repeat, || S::solve2(&parsed)),
+            };
+            (part, stats)
+        })
+        .collect();
+
+    Ok(DayBench {
+        day: S::DAY,
+        parse,
+        parts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=100).rev().map(Duration::from_micros).collect();
+        let stats = Stats::from_samples(samples);
+        assert_eq!(stats.samples, 100);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(50));
+        assert_eq!(stats.p90, Duration::from_micros(90));
+        assert_eq!(stats.p99, Duration::from_micros(99));
+        assert_eq!(stats.max, Duration::from_micros(100));
+        assert_eq!(stats.mean, Duration::from_nanos(50_500));
+
+        let stats = Stats::from_samples(vec![Duration::from_millis(3)]);
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p99, Duration::from_millis(3));
+        assert_eq!(
+            stats.to_string(),
+            "median     3.00ms  min     3.00ms  max     3.00ms"
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use std::env;
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::io::{self, IsTerminal, Read};
+use std::path::{Path, PathBuf};
+
+/// Where to read a day's puzzle input from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    /// Stdin if anything was piped to it, otherwise `Default`.
+    StdinOrDefault,
+    /// `inputs/dayNN.txt` in the current directory or one of its parents.
+    Default,
+}
+
+impl InputSource {
+    /// Pick a source from an optional command line argument. `-` means stdin,
+    /// and with no argument a non-empty piped stdin is preferred over the
+    /// default file.
+    pub fn from_arg(arg: Option<OsString>) -> Self {
+        match arg {
+            Some(arg) if arg == "-" => InputSource::Stdin,
+            Some(arg) => InputSource::File(PathBuf::from(arg)),
+            None if !io::stdin().is_terminal() => InputSource::StdinOrDefault,
+            None => InputSource::Default,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound {
+        day: u8,
+        path: PathBuf,
+    },
+    Io {
+        path: Option<PathBuf>,
+        source: io::Error,
+    },
+    EmptyStdin,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { day, path } => write!(
+                f,
+                "no input for day {} at {}; save your puzzle input there or pass a path",
+                day,
+                path.display()
+            ),
+            InputError::Io {
+                path: Some(path),
+                source,
+            } => write!(f, "could not read {}: {}", path.display(), source),
+            InputError::Io { path: None, source } => {
+                write!(f, "could not read stdin: {}", source)
+            }
+            InputError::EmptyStdin => write!(f, "no input on stdin"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } | InputError::EmptyStdin => None,
+            InputError::Io { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Conventional location of a day's input, relative to the workspace root.
+pub fn default_input_path(day: u8) -> PathBuf {
+    Path::new("inputs").join(format!("day{:02}.txt", day))
+}
+
+/// Look for `relative` in the current directory and each of its parents.
+pub fn find_upwards(relative: &Path) -> io::Result<Option<PathBuf>> {
+    Ok(find_upwards_from(&env::current_dir()?, relative))
+}
+
+fn find_upwards_from(dir: &Path, relative: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|dir| dir.join(relative))
+        .find(|path| path.is_file())
+}
+
+fn find_default_input(day: u8) -> Result<PathBuf, InputError> {
+    let relative = default_input_path(day);
+    find_upwards(&relative)
+        .map_err(|source| InputError::Io { path: None, source })?
+        .ok_or(InputError::NotFound {
+            day,
+            path: relative,
+        })
+}
+
+fn read_stdin() -> Result<String, InputError> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|source| InputError::Io { path: None, source })?;
+    Ok(input)
+}
+
+/// Read a day's puzzle input.
+pub fn load_input(day: u8, source: &InputSource) -> Result<String, InputError> {
+    let path = match source {
+        InputSource::File(path) => path.clone(),
+        InputSource::Stdin => {
+            let input = read_stdin()?;
+            if input.trim().is_empty() {
+                return Err(InputError::EmptyStdin);
+            }
+            return Ok(input);
+        }
+        InputSource::StdinOrDefault => {
+            let input = read_stdin()?;
+            if !input.trim().is_empty() {
+                return Ok(input);
+            }
+            find_default_input(day)?
+        }
+        InputSource::Default => find_default_input(day)?,
+    };
+
+    fs::read_to_string(&path).map_err(|source| InputError::Io {
+        path: Some(path),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("common-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg(Some("-".into())), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(Some("day01.txt".into())),
+            InputSource::File(PathBuf::from("day01.txt"))
+        );
+        assert!(matches!(
+            InputSource::from_arg(None),
+            InputSource::StdinOrDefault | InputSource::Default
+        ));
+    }
+
+    #[test]
+    fn test_find_upwards() {
+        let root = temp_dir("find");
+        let nested = root.join("a").join("b");
+        fs::create_dir_all(&nested).unwrap();
+        let relative = default_input_path(1);
+        assert_eq!(find_upwards_from(&nested, &relative), None);
+
+        fs::create_dir_all(root.join("inputs")).unwrap();
+        fs::write(root.join(&relative), "199\n").unwrap();
+        assert_eq!(
+            find_upwards_from(&nested, &relative),
+            Some(root.join(&relative))
+        );
+        assert_eq!(
+            find_upwards_from(&root, &relative),
+            Some(root.join(&relative))
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_load_input() {
+        let root = temp_dir("load");
+        let path = root.join("input.txt");
+        fs::write(&path, "199\n200\n").unwrap();
+        let input = load_input(1, &InputSource::File(path.clone())).unwrap();
+        assert_eq!(input, "199\n200\n");
+
+        let missing = root.join("missing.txt");
+        match load_input(1, &InputSource::File(missing.clone())) {
+            Err(InputError::Io { path, .. }) => assert_eq!(path, Some(missing)),
+            other => panic!("expected an I/O error, got {:?}", other),
+        }
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,161 @@
+pub mod bench;
+mod input;
+mod parse;
+
+pub use input::{default_input_path, find_upwards, load_input, InputError, InputSource};
+pub use parse::{content_lines, parse_digit_rows, parse_number, single_line, ParseError};
+
+use std::env;
+use std::ffi::OsString;
+use std::fmt::Display;
+use std::process;
+use std::time::{Duration, Instant};
+
+/// A single day's puzzle, split into input parsing and the two parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input<'a>;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn solve1(input: &Self::Input<'_>) -> Self::Answer1;
+    fn solve2(input: &Self::Input<'_>) -> Self::Answer2;
+}
+
+/// A part's answer as the runners print it, or why there is none.
+pub trait Answer {
+    fn to_answer(&self) -> Result<String, String>;
+}
+
+impl<T: Display> Answer for T {
+    fn to_answer(&self) -> Result<String, String> {
+        Ok(self.to_string())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: &'static [Part] = &[Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PartResult {
+    pub part: Part,
+    /// The answer, or why the part failed.
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug)]
+pub struct DayResult {
+    pub day: u8,
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+/// Parse `input` and solve the requested parts, timing each stage.
+pub fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<DayResult, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_elapsed = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::solve1(&parsed).to_answer(),
+                Part::Two => S::solve2(&parsed).to_answer(),
+            };
+            PartResult {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(DayResult {
+        day: S::DAY,
+        parse_elapsed,
+        parts,
+    })
+}
+
+/// Print both answers the way each day's standalone binary always has.
+/// Returns false if a part failed.
+pub fn print_answers<S: Solution>(input: &str) -> Result<bool, ParseError> {
+    let result = run::<S>(input, Part::ALL)?;
+    let mut all_solved = true;
+    for part in result.parts {
+        match part.answer {
+            Ok(answer) if answer.contains('\n') => {
+                println!("part {}:\n{}", part.part.number(), answer)
+            }
+            Ok(answer) => println!("part {}: {}", part.part.number(), answer),
+            Err(err) => {
+                eprintln!("error: part {}: {}", part.part.number(), err);
+                all_solved = false;
+            }
+        }
+    }
+
+    Ok(all_solved)
+}
+
+/// Load a day's input from an optional command line argument, exiting with
+/// the error if it cannot be read.
+pub fn load_or_exit<S: Solution>(arg: Option<OsString>) -> String {
+    load_input(S::DAY, &InputSource::from_arg(arg)).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    })
+}
+
+/// Parse a day's input, exiting with a diagnostic if it is malformed.
+pub fn parse_or_exit<S: Solution>(input: &str) -> S::Input<'_> {
+    S::parse(input).unwrap_or_else(|err| {
+        eprintln!("error: {}", err.diagnostic(input));
+        process::exit(1);
+    })
+}
+
+/// Entry point for each day's binary: `dayN [path|-]`, falling back to a
+/// piped stdin and then `inputs/dayNN.txt`.
+pub fn main<S: Solution>() {
+    let mut args = env::args_os().skip(1);
+    let path = args.next();
+    if let Some(arg) = args.next() {
+        eprintln!(
+            "error: unexpected argument: {}\nusage: day{} [path|-]",
+            arg.to_string_lossy(),
+            S::DAY
+        );
+        process::exit(2);
+    }
+
+    let input = load_or_exit::<S>(path);
+
+    match print_answers::<S>(&input) {
+        Ok(true) => (),
+        Ok(false) => process::exit(1),
+        Err(err) => {
+            eprintln!("error: {}", err.diagnostic(&input));
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A malformed piece of puzzle input, located by 1-based line and column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error for `text`, which should be a subslice of `line`. `line_idx` is
+    /// zero-based, as produced by `enumerate()`.
+    pub fn at(line_idx: usize, line: &str, text: &str, message: impl Into<String>) -> Self {
+        let start = line.as_ptr() as usize;
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset <= line.len())
+            .unwrap_or(0);
+
+        Self {
+            line: line_idx + 1,
+            column: line[..offset].chars().count() + 1,
+            text: text.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Error for something missing after the end of `line`.
+    pub fn at_end(line_idx: usize, line: &str, message: impl Into<String>) -> Self {
+        Self::at(line_idx, line, &line[line.len()..], message)
+    }
+
+    /// The error followed by the offending line with the text underlined.
+    pub fn diagnostic(&self, input: &str) -> String {
+        let mut output = self.to_string();
+        if let Some(source) = input.lines().nth(self.line - 1) {
+            let gutter = " ".repeat(self.line.to_string().len());
+            let underline = "^".repeat(self.text.chars().count().max(1));
+            output.push_str(&format!(
+                "\n{} |\n{} | {}\n{} | {}{}",
+                gutter,
+                self.line,
+                source,
+                gutter,
+                " ".repeat(self.column - 1),
+                underline
+            ));
+        }
+
+        output
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " `{}`", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse `text`, a subslice of `line`, as a number.
+pub fn parse_number<T: FromStr>(line_idx: usize, line: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::at(line_idx, line, text, "invalid number"))
+}
+
+/// Lines of `input` numbered from zero as by `enumerate()`, without the
+/// blank lines before and after the content, so errors still point at the
+/// right line.
+pub fn content_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .trim_end()
+        .lines()
+        .enumerate()
+        .skip_while(|(_, line)| line.trim().is_empty())
+}
+
+/// The one line of an input that should only have one, numbered from zero.
+pub fn single_line(input: &str) -> Result<(usize, &str), ParseError> {
+    let mut lines = content_lines(input);
+    let first = lines.next().unwrap_or((0, ""));
+    match lines.next() {
+        Some((idx, line)) => Err(ParseError::at(idx, line, line, "expected a single line")),
+        None => Ok(first),
+    }
+}
+
+/// Parse rows of single digits, requiring every row to be the same length.
+pub fn parse_digit_rows(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    let mut rows: Vec<Vec<u32>> = Vec::new();
+    for (idx, line) in content_lines(input) {
+        let row = line
+            .char_indices()
+            .map(|(pos, c)| {
+                c.to_digit(10).ok_or_else(|| {
+                    ParseError::at(
+                        idx,
+                        line,
+                        &line[pos..pos + c.len_utf8()],
+                        "expected a digit",
+                    )
+                })
+            })
+            .collect::<Result<Vec<u32>, _>>()?;
+
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(ParseError::at(
+                    idx,
+                    line,
+                    line,
+                    format!("expected {} digits", first.len()),
+                ));
+            }
+        }
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column() {
+        let line = "fold along z=7";
+        let err = ParseError::at(4, line, &line[11..12], "expected fold axis x or y");
+        assert_eq!((err.line, err.column), (5, 12));
+        assert_eq!(
+            err.to_string(),
+            "line 5, column 12: expected fold axis x or y `z`"
+        );
+
+        let err = ParseError::at_end(0, "forward", "expected a distance");
+        assert_eq!(err.column, 8);
+        assert!(err.text.is_empty());
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let input = "2199943210\n39878x4921\n";
+        let err = parse_digit_rows(input).unwrap_err();
+        assert_eq!(
+            err.diagnostic(input),
+            "line 2, column 6: expected a digit `x`\n  |\n2 | 39878x4921\n  |      ^"
+        );
+    }
+
+    #[test]
+    fn test_content_lines() {
+        let lines: Vec<_> = content_lines("\n \n12\n\n34\n\n").collect();
+        assert_eq!(lines, [(2, "12"), (3, ""), (4, "34")]);
+
+        let err = parse_digit_rows("\n\n12\n3x").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 2));
+
+        assert_eq!(single_line("\n1,2\n"), Ok((1, "1,2")));
+        let err = single_line("1,2\n3,x").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "3,x"));
+    }
+}
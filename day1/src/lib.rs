@@ -0,0 +1,81 @@
+use common::{content_lines, parse_number, ParseError, Solution};
+
+fn solve1(input: &[i32]) -> i32 {
+    let mut increased = 0;
+    let mut prev = input[0];
+    for val in input.iter().skip(1) {
+        if *val > prev {
+            increased += 1;
+        }
+        prev = *val;
+    }
+
+    increased
+}
+
+fn solve2(input: &[i32]) -> i32 {
+    let mut increased = 0;
+    let mut prev = None;
+    for window in input.windows(3) {
+        let val: i32 = window.iter().sum();
+        if let Some(prev) = prev {
+            if val > prev {
+                increased += 1;
+            }
+        }
+
+        prev = Some(val);
+    }
+
+    increased
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        if input.trim().is_empty() {
+            return Err(ParseError::at_end(0, "", "expected depth measurements"));
+        }
+        content_lines(input)
+            .map(|(idx, line)| parse_number(idx, line, line.trim()))
+            .collect()
+    }
+
+    fn solve1(input: &Vec<i32>) -> i32 {
+        solve1(input)
+    }
+
+    fn solve2(input: &Vec<i32>) -> i32 {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &[i32] = &[199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(solve1(INPUT), 7);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(solve2(INPUT), 5);
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let err = Day1::parse("\n").unwrap_err();
+        assert_eq!(err.message, "expected depth measurements");
+    }
+}
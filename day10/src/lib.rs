@@ -0,0 +1,163 @@
+use common::{content_lines, ParseError, Solution};
+
+fn solve1(input: &[&str]) -> i32 {
+    let mut score = 0;
+
+    for line in input.iter() {
+        let mut char_stack = Vec::new();
+
+        for chr in line.chars() {
+            if let '(' | '[' | '{' | '<' = chr {
+                char_stack.push(chr);
+            } else {
+                let opener = char_stack.pop().unwrap();
+                let bad_match_score = match chr {
+                    ')' if opener != '(' => Some(3),
+                    ']' if opener != '[' => Some(57),
+                    '}' if opener != '{' => Some(1197),
+                    '>' if opener != '<' => Some(25137),
+                    _ => None,
+                };
+
+                if let Some(bad_match_score) = bad_match_score {
+                    score += bad_match_score;
+                    break;
+                }
+            }
+        }
+    }
+
+    score
+}
+
+fn solve2(input: &[&str]) -> u64 {
+    let mut line_scores = Vec::new();
+
+    for line in input.iter() {
+        let mut char_stack = Vec::new();
+        let mut invalid_line = false;
+
+        for chr in line.chars() {
+            if let '(' | '[' | '{' | '<' = chr {
+                char_stack.push(chr);
+            } else {
+                let opener = char_stack.pop().unwrap();
+
+                invalid_line = match chr {
+                    ')' if opener != '(' => true,
+                    ']' if opener != '[' => true,
+                    '}' if opener != '{' => true,
+                    '>' if opener != '<' => true,
+                    _ => false,
+                };
+
+                if invalid_line {
+                    break;
+                }
+            }
+        }
+
+        if !invalid_line {
+            let mut line_score = 0;
+            for chr in char_stack.iter().rev() {
+                match chr {
+                    '(' => line_score = line_score * 5 + 1,
+                    '[' => line_score = line_score * 5 + 2,
+                    '{' => line_score = line_score * 5 + 3,
+                    '<' => line_score = line_score * 5 + 4,
+                    _ => (),
+                }
+            }
+
+            line_scores.push(line_score);
+        }
+    }
+
+    line_scores.sort_unstable();
+
+    line_scores[line_scores.len() / 2]
+}
+
+fn parse_input(input: &str) -> Result<Vec<&str>, ParseError> {
+    let mut parsed = Vec::new();
+    for (idx, line) in content_lines(input) {
+        let mut depth = 0;
+        for (pos, chr) in line.char_indices() {
+            let text = &line[pos..pos + chr.len_utf8()];
+            match chr {
+                '(' | '[' | '{' | '<' => depth += 1,
+                ')' | ']' | '}' | '>' if depth == 0 => {
+                    return Err(ParseError::at(idx, line, text, "unmatched closing bracket"));
+                }
+                ')' | ']' | '}' | '>' => depth -= 1,
+                _ => return Err(ParseError::at(idx, line, text, "expected a bracket")),
+            }
+        }
+        parsed.push(line);
+    }
+    if parsed.is_empty() {
+        return Err(ParseError::at_end(0, "", "expected lines of brackets"));
+    }
+
+    Ok(parsed)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = Vec<&'a str>;
+    type Answer1 = i32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        parse_input(input)
+    }
+
+    fn solve1(input: &Vec<&str>) -> i32 {
+        solve1(input)
+    }
+
+    fn solve2(input: &Vec<&str>) -> u64 {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "[({(<(())[]>[[{[]{<()<>>",
+        "[(()[<>])]({[<{<<[]>>(",
+        "{([(<{}[<>[]}>{[]{[(<()>",
+        "(((({<>}<{<{<>}{[]{[]{}",
+        "[[<[([]))<([[{}[[()]]]",
+        "[{[{({}]{}}([{[{{{}}([]",
+        "{<[[]]>}<{[{[{[]{()[[[]",
+        "[<(<(<(<{}))><([]([]()",
+        "<{([([[(<>()){}]>(<<{{",
+        "<{([{{}}[<[[[<>{}]]]>[]]",
+    ];
+
+    #[test]
+    fn test_parse_empty() {
+        let err = Day10::parse("\n\n").unwrap_err();
+        assert_eq!(err.message, "expected lines of brackets");
+
+        // Leading blank lines still count.
+        let err = Day10::parse("\n\n(]x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+    }
+
+    #[test]
+    fn test_solve1() {
+        assert_eq!(solve1(INPUT), 26397);
+    }
+
+    #[test]
+    fn test_solve2() {
+        assert_eq!(solve2(INPUT), 288957);
+    }
+}
@@ -0,0 +1,215 @@
+use common::{ParseError, Solution};
+use grid::Grid;
+
+pub mod visualize;
+
+/// What happened during one step of the simulation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StepReport {
+    /// 1-based number of this step.
+    pub step: usize,
+    pub flashes: usize,
+    /// Cells that flashed, in the order they flashed.
+    pub flashed: Vec<(usize, usize)>,
+    /// Number of waves of flashes, 0 if nothing flashed.
+    pub cascade_depth: usize,
+}
+
+/// A grid of octopus energy levels that can be stepped through.
+#[derive(Clone, Debug)]
+pub struct OctopusGrid {
+    energy: Grid<u32>,
+    steps: usize,
+}
+
+impl OctopusGrid {
+    pub fn new(energy: Grid<u32>) -> Self {
+        Self { energy, steps: 0 }
+    }
+
+    pub fn energy(&self) -> &Grid<u32> {
+        &self.energy
+    }
+
+    /// Number of steps taken so far.
+    pub fn step_count(&self) -> usize {
+        self.steps
+    }
+
+    /// Advance one step: raise every energy level, flash in waves until
+    /// nothing is above 9, then reset the flashed cells to 0.
+    ///
+    /// A cell flashes exactly when its energy reaches 10, so each flash is
+    /// queued once and the grid is only scanned for the first wave.
+    pub fn step(&mut self) -> StepReport {
+        for energy in self.energy.iter_mut() {
+            *energy += 1;
+        }
+
+        let mut wave: Vec<_> = self
+            .energy
+            .positions()
+            .filter(|&pos| self.energy[pos] > 9)
+            .collect();
+        let mut order = Vec::new();
+        let mut cascade_depth = 0;
+
+        while !wave.is_empty() {
+            cascade_depth += 1;
+            let mut next = Vec::new();
+            for &(x, y) in &wave {
+                for neighbour in self.energy.neighbours8(x, y) {
+                    self.energy[neighbour] += 1;
+                    if self.energy[neighbour] == 10 {
+                        next.push(neighbour);
+                    }
+                }
+            }
+            order.append(&mut wave);
+            wave = next;
+        }
+
+        for &pos in &order {
+            self.energy[pos] = 0;
+        }
+
+        self.steps += 1;
+        StepReport {
+            step: self.steps,
+            flashes: order.len(),
+            flashed: order,
+            cascade_depth,
+        }
+    }
+
+    /// Endless iterator stepping the simulation.
+    pub fn steps(&mut self) -> impl Iterator<Item = StepReport> + '_ {
+        std::iter::from_fn(move || Some(self.step()))
+    }
+
+    /// Total flashes over the next `steps` steps.
+    pub fn flashes_after(&mut self, steps: usize) -> usize {
+        self.steps().take(steps).map(|report| report.flashes).sum()
+    }
+
+    /// Step until every octopus flashes at once and return that step's number.
+    pub fn first_sync(&mut self) -> usize {
+        let size = self.energy.len();
+        self.steps()
+            .find(|report| report.flashes == size)
+            .map(|report| report.step)
+            .unwrap()
+    }
+}
+
+fn solve1(input: Grid<u32>) -> usize {
+    OctopusGrid::new(input).flashes_after(100)
+}
+
+fn solve2(input: Grid<u32>) -> usize {
+    OctopusGrid::new(input).first_sync()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = Grid<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse_digits(input)
+    }
+
+    fn solve1(input: &Grid<u32>) -> usize {
+        solve1(input.clone())
+    }
+
+    fn solve2(input: &Grid<u32>) -> usize {
+        solve2(input.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&[u32]] = &[
+        &[5, 4, 8, 3, 1, 4, 3, 2, 2, 3],
+        &[2, 7, 4, 5, 8, 5, 4, 7, 1, 1],
+        &[5, 2, 6, 4, 5, 5, 6, 1, 7, 3],
+        &[6, 1, 4, 1, 3, 3, 6, 1, 4, 6],
+        &[6, 3, 5, 7, 3, 8, 5, 4, 7, 8],
+        &[4, 1, 6, 7, 5, 2, 4, 6, 4, 5],
+        &[2, 1, 7, 6, 8, 4, 1, 7, 2, 1],
+        &[6, 8, 8, 2, 8, 8, 1, 1, 3, 4],
+        &[4, 8, 4, 6, 8, 4, 8, 5, 5, 4],
+        &[5, 2, 8, 3, 7, 5, 1, 5, 2, 6],
+    ];
+
+    #[test]
+    fn test_solve1() {
+        let input = Grid::from_rows(INPUT.iter().map(|row| row.to_vec()).collect());
+
+        assert_eq!(solve1(input.clone()), 1656);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input = Grid::from_rows(INPUT.iter().map(|row| row.to_vec()).collect());
+
+        assert_eq!(solve2(input.clone()), 195);
+    }
+
+    #[test]
+    fn test_step_report() {
+        let input = Grid::parse_digits("11111\n19991\n19191\n19991\n11111").unwrap();
+        let mut octopuses = OctopusGrid::new(input);
+
+        let report = octopuses.step();
+        assert_eq!(report.step, 1);
+        assert_eq!(report.flashes, 9);
+        assert_eq!(report.cascade_depth, 2);
+        assert_eq!(report.flashed.last(), Some(&(2, 2)));
+        assert_eq!(
+            octopuses.energy(),
+            &Grid::parse_digits("34543\n40004\n50005\n40004\n34543").unwrap()
+        );
+
+        let report = octopuses.steps().next().unwrap();
+        assert_eq!(
+            (report.step, report.flashes, report.cascade_depth),
+            (2, 0, 0)
+        );
+        assert_eq!(octopuses.step_count(), 2);
+    }
+
+    #[test]
+    fn test_large_cascade() {
+        let mut input = Grid::new(1000, 1000, 8);
+        input[(0, 0)] = 9;
+        let mut octopuses = OctopusGrid::new(input);
+
+        let report = octopuses.step();
+        assert_eq!(report.flashes, 1_000_000);
+        assert_eq!(report.cascade_depth, 1000);
+        assert!(octopuses.energy().iter().all(|&energy| energy == 0));
+    }
+
+    #[test]
+    fn test_non_square() {
+        // Everything flashes on the first step and then every tenth step.
+        let input = Grid::from_rows(vec![vec![9, 9, 9], vec![9, 9, 9]]);
+        assert_eq!(solve1(input.clone()), 60);
+        assert_eq!(solve2(input), 1);
+
+        // A single flash cascades along the whole row.
+        let input = Grid::from_rows(vec![vec![8, 8, 9, 8, 8, 8, 8]]);
+        assert_eq!(solve2(input.clone()), 1);
+
+        let input = Grid::parse_digits("1\n9\n1\n1").unwrap();
+        assert_eq!(solve2(input), 17);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{OctopusGrid, StepReport};
+use grid::Grid;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+/// 256-colour palette indices for energy levels 1 to 9, dark blue to yellow.
+const GRADIENT: [u8; 9] = [17, 18, 19, 25, 31, 37, 71, 142, 184];
+
+const FLASH: &str = "\x1b[1;97m";
+const RESET: &str = "\x1b[0m";
+const HOME: &str = "\x1b[H";
+const CLEAR: &str = "\x1b[2J";
+
+pub struct Options {
+    pub delay: Duration,
+    /// Number of steps to show, unless `until_sync` stops earlier.
+    pub steps: usize,
+    /// Stop after the first step where every octopus flashes.
+    pub until_sync: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(100),
+            steps: 100,
+            until_sync: false,
+        }
+    }
+}
+
+/// One frame: a status line followed by the grid, flashes in bright white
+/// and other cells coloured by energy level.
+pub fn render_frame(energy: &Grid<u32>, report: Option<&StepReport>) -> String {
+    let mut frame = match report {
+        Some(report) => format!(
+            "step {:>4}  flashes {:>4}  depth {:>3}\n",
+            report.step, report.flashes, report.cascade_depth
+        ),
+        None => "step    0\n".to_owned(),
+    };
+
+    for row in energy.rows() {
+        for &level in row {
+            match level {
+                0 => frame.push_str(FLASH),
+                level => frame.push_str(&format!(
+                    "\x1b[38;5;{}m",
+                    GRADIENT[level.min(9) as usize - 1]
+                )),
+            }
+            frame.push_str(&level.to_string());
+        }
+        frame.push_str(RESET);
+        frame.push('\n');
+    }
+
+    frame
+}
+
+/// Animate `octopuses` on `out`, redrawing in place every `options.delay`.
+/// Returns the last step shown.
+pub fn animate(
+    octopuses: &mut OctopusGrid,
+    options: &Options,
+    out: &mut impl Write,
+) -> io::Result<usize> {
+    let size = octopuses.energy().len();
+    write!(out, "{}{}", CLEAR, HOME)?;
+    write!(out, "{}", render_frame(octopuses.energy(), None))?;
+    out.flush()?;
+
+    for _ in 0..options.steps {
+        thread::sleep(options.delay);
+        let report = octopuses.step();
+        write!(
+            out,
+            "{}{}",
+            HOME,
+            render_frame(octopuses.energy(), Some(&report))
+        )?;
+        out.flush()?;
+
+        if options.until_sync && report.flashes == size {
+            break;
+        }
+    }
+
+    Ok(octopuses.step_count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_frame() {
+        let mut octopuses = OctopusGrid::new(Grid::from_rows(vec![vec![9, 1]]));
+        let report = octopuses.step();
+
+        let frame = render_frame(octopuses.energy(), Some(&report));
+        assert_eq!(
+            frame,
+            "step    1  flashes    1  depth   1\n\
+             \x1b[1;97m0\x1b[38;5;19m3\x1b[0m\n"
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use common::{ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum CaveNode<'a> {
+    Big(&'a str),
+    Small(&'a str),
+    Start,
+    End,
+}
+
+type CaveMap<'a> = HashMap<CaveNode<'a>, HashSet<CaveNode<'a>>>;
+
+fn parse_node<'a>(idx: usize, line: &str, name: &'a str) -> Result<CaveNode<'a>, ParseError> {
+    match name {
+        "start" => Ok(CaveNode::Start),
+        "end" => Ok(CaveNode::End),
+        _ => match name.chars().next() {
+            Some(first) if first.is_uppercase() => Ok(CaveNode::Big(name)),
+            Some(_) => Ok(CaveNode::Small(name)),
+            None => Err(ParseError::at(idx, line, name, "expected a cave name")),
+        },
+    }
+}
+
+fn parse_input<'a>(input: &[&'a str]) -> Result<CaveMap<'a>, ParseError> {
+    let mut map = HashMap::new();
+    let lines = input.iter().enumerate();
+    for (idx, &line) in lines.skip_while(|(_, line)| line.trim().is_empty()) {
+        let (left, right) = line
+            .trim()
+            .split_once('-')
+            .ok_or_else(|| ParseError::at(idx, line, line, "expected `cave-cave`"))?;
+        let left_node = parse_node(idx, line, left)?;
+        let right_node = parse_node(idx, line, right)?;
+
+        let mapping = map.entry(left_node.clone()).or_insert_with(HashSet::new);
+        mapping.insert(right_node.clone());
+
+        let mapping = map.entry(right_node.clone()).or_insert_with(HashSet::new);
+        mapping.insert(left_node);
+    }
+
+    Ok(map)
+}
+
+fn visit_nodes1<'a>(
+    cur_node: &'a CaveNode,
+    visited: &mut HashSet<&'a CaveNode<'a>>,
+    map: &'a HashMap<CaveNode, HashSet<CaveNode<'a>>>,
+) -> u64 {
+    let mut paths_found = 0;
+
+    if *cur_node == CaveNode::End {
+        return 1;
+    }
+
+    if let Some(next_node) = map.get(cur_node) {
+        for next_node in next_node.iter() {
+            match next_node {
+                CaveNode::Start => (),
+                CaveNode::End | CaveNode::Big(_) => {
+                    paths_found += visit_nodes1(next_node, visited, map);
+                }
+                CaveNode::Small(_) => {
+                    if visited.insert(next_node) {
+                        paths_found += visit_nodes1(next_node, visited, map);
+                        visited.remove(next_node);
+                    }
+                }
+            }
+        }
+    }
+
+    paths_found
+}
+
+fn solve1(input: &HashMap<CaveNode, HashSet<CaveNode>>) -> u64 {
+    let mut visited = HashSet::new();
+    visited.insert(&CaveNode::Start);
+
+    visit_nodes1(&CaveNode::Start, &mut visited, input)
+}
+
+fn visit_nodes2<'a>(
+    cur_node: &'a CaveNode,
+    visited: &mut HashMap<&'a CaveNode<'a>, u8>,
+    map: &'a HashMap<CaveNode, HashSet<CaveNode<'a>>>,
+    double_small_visit: bool,
+) -> u64 {
+    let mut paths_found = 0;
+
+    if *cur_node == CaveNode::End {
+        return 1;
+    }
+
+    if let Some(next_node) = map.get(cur_node) {
+        for next_node in next_node.iter() {
+            match next_node {
+                CaveNode::Start => (),
+                CaveNode::End | CaveNode::Big(_) => {
+                    paths_found += visit_nodes2(next_node, visited, map, double_small_visit);
+                }
+                CaveNode::Small(_) => {
+                    let tracker = visited.entry(next_node).or_insert(0);
+                    if *tracker == 0 {
+                        *tracker += 1;
+                        paths_found += visit_nodes2(next_node, visited, map, double_small_visit);
+                        *visited.get_mut(next_node).unwrap() -= 1;
+                    } else if *tracker == 1 && !double_small_visit {
+                        *tracker += 1;
+                        paths_found += visit_nodes2(next_node, visited, map, true);
+                        *visited.get_mut(next_node).unwrap() -= 1;
+                    }
+                }
+            }
+        }
+    }
+
+    paths_found
+}
+
+fn solve2(input: &HashMap<CaveNode, HashSet<CaveNode>>) -> u64 {
+    let mut visited: HashMap<&CaveNode, u8> = HashMap::new();
+
+    visit_nodes2(&CaveNode::Start, &mut visited, input, false)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input<'a> = CaveMap<'a>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<CaveMap<'_>, ParseError> {
+        let input: Vec<&str> = input.trim_end().lines().collect();
+        parse_input(&input)
+    }
+
+    fn solve1(input: &CaveMap) -> u64 {
+        solve1(input)
+    }
+
+    fn solve2(input: &CaveMap) -> u64 {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &["start-A", "start-b", "A-c", "A-b", "b-d", "A-end", "b-end"];
+
+    #[test]
+    fn test_parse() {
+        let parsed = parse_input(INPUT).unwrap();
+        assert!(parsed
+            .get(&CaveNode::Start)
+            .unwrap()
+            .contains(&CaveNode::Big("A")));
+
+        assert!(parsed
+            .get(&CaveNode::Small("b"))
+            .unwrap()
+            .contains(&CaveNode::Big("A")));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input(&["", "start-A", "A"]).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+
+    #[test]
+    fn test_solve1() {
+        let parsed = parse_input(INPUT).unwrap();
+        assert_eq!(solve1(&parsed), 10);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let parsed = parse_input(INPUT).unwrap();
+        assert_eq!(solve2(&parsed), 36);
+    }
+}
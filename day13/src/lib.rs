@@ -0,0 +1,218 @@
+use common::{parse_number, ParseError, Solution};
+use grid::SparseGrid;
+
+pub enum Fold {
+    X(isize),
+    Y(isize),
+}
+
+type Paper = SparseGrid<()>;
+
+fn parse_input(input: &[&str]) -> Result<(Paper, Vec<Fold>), ParseError> {
+    let mut map = SparseGrid::new();
+
+    let mut lines = input
+        .iter()
+        .enumerate()
+        .skip_while(|(_, line)| line.trim().is_empty());
+    for (idx, line) in &mut lines {
+        // break when get to folds
+        if line.is_empty() {
+            break;
+        }
+
+        let (left, right) = line
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(idx, line, line, "expected `x,y`"))?;
+        let left = parse_number(idx, line, left)?;
+        let right = parse_number(idx, line, right)?;
+        map.insert(left, right, ());
+    }
+
+    let mut folds = Vec::new();
+    for (idx, line) in lines {
+        let (left, right) = line
+            .split_once('=')
+            .ok_or_else(|| ParseError::at(idx, line, line, "expected `fold along x=N`"))?;
+        let axis = &left[left.rfind(' ').map_or(0, |pos| pos + 1)..];
+        let right = parse_number(idx, line, right)?;
+
+        match axis {
+            "x" => folds.push(Fold::X(right)),
+            "y" => folds.push(Fold::Y(right)),
+            _ => return Err(ParseError::at(idx, line, axis, "expected fold axis x or y")),
+        }
+    }
+
+    if folds.is_empty() {
+        let idx = input.len().saturating_sub(1);
+        let line = input.last().copied().unwrap_or("");
+        return Err(ParseError::at_end(idx, line, "expected fold instructions"));
+    }
+
+    Ok((map, folds))
+}
+
+fn solve1(mut input: Paper, folds: &[Fold]) -> usize {
+    match folds[0] {
+        Fold::X(xline) => {
+            let mut remove_list = Vec::new();
+            let mut add_list = Vec::new();
+            for (x, y) in input.positions() {
+                if x > xline {
+                    let new_x = xline - (x - xline);
+                    remove_list.push((x, y));
+                    add_list.push((new_x, y));
+                }
+            }
+
+            for (x, y) in remove_list {
+                input.remove(x, y);
+            }
+
+            for (x, y) in add_list {
+                input.insert(x, y, ());
+            }
+        }
+        Fold::Y(yline) => {
+            let mut remove_list = Vec::new();
+            let mut add_list = Vec::new();
+            for (x, y) in input.positions() {
+                if y > yline {
+                    let new_y = yline - (y - yline);
+                    remove_list.push((x, y));
+                    add_list.push((x, new_y));
+                }
+            }
+
+            for (x, y) in remove_list {
+                input.remove(x, y);
+            }
+
+            for (x, y) in add_list {
+                input.insert(x, y, ());
+            }
+        }
+    }
+
+    input.len()
+}
+
+fn solve2(mut input: Paper, folds: &[Fold]) -> String {
+    for fold in folds {
+        match *fold {
+            Fold::X(xline) => {
+                let mut remove_list = Vec::new();
+                let mut add_list = Vec::new();
+                for (x, y) in input.positions() {
+                    if x > xline {
+                        let new_x = xline - (x - xline);
+                        remove_list.push((x, y));
+                        add_list.push((new_x, y));
+                    }
+                }
+
+                for (x, y) in remove_list {
+                    input.remove(x, y);
+                }
+
+                for (x, y) in add_list {
+                    input.insert(x, y, ());
+                }
+            }
+            Fold::Y(yline) => {
+                let mut remove_list = Vec::new();
+                let mut add_list = Vec::new();
+                for (x, y) in input.positions() {
+                    if y > yline {
+                        let new_y = yline - (y - yline);
+                        remove_list.push((x, y));
+                        add_list.push((x, new_y));
+                    }
+                }
+
+                for (x, y) in remove_list {
+                    input.remove(x, y);
+                }
+
+                for (x, y) in add_list {
+                    input.insert(x, y, ());
+                }
+            }
+        }
+    }
+
+    input.render_from((0, 0))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input<'a> = (Paper, Vec<Fold>);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<(Paper, Vec<Fold>), ParseError> {
+        let input: Vec<&str> = input.trim_end().lines().collect();
+        parse_input(&input)
+    }
+
+    fn solve1((map, folds): &(Paper, Vec<Fold>)) -> usize {
+        solve1(map.clone(), folds)
+    }
+
+    fn solve2((map, folds): &(Paper, Vec<Fold>)) -> String {
+        solve2(map.clone(), folds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "6,10",
+        "0,14",
+        "9,10",
+        "0,3",
+        "10,4",
+        "4,11",
+        "6,0",
+        "6,12",
+        "4,1",
+        "0,13",
+        "10,12",
+        "3,4",
+        "3,0",
+        "8,4",
+        "1,10",
+        "2,14",
+        "8,10",
+        "9,0",
+        "",
+        "fold along y=7",
+        "fold along x=5",
+    ];
+
+    #[test]
+    fn test_solve1() {
+        let (grid, folds) = parse_input(INPUT).unwrap();
+        assert_eq!(solve1(grid, &folds), 17);
+    }
+
+    #[test]
+    fn test_solve2_origin() {
+        let (grid, folds) = parse_input(&["1,1", "1,3", "", "fold along y=2"]).unwrap();
+        assert_eq!(solve2(grid, &folds), "..\n.#\n");
+    }
+
+    #[test]
+    fn test_missing_folds() {
+        let err = parse_input(&INPUT[..18]).err().unwrap();
+        assert_eq!((err.line, err.column), (18, 4));
+        assert_eq!(err.message, "expected fold instructions");
+        assert!(Day13::parse("").is_err());
+    }
+}
@@ -0,0 +1,161 @@
+use common::Solution;
+use day16::random::{random_packet, Rng};
+use day16::{
+    compile, decode_hex, dump, Day16, Format, LengthType, StreamDecoder, DEFAULT_MAX_DEPTH,
+};
+use std::env;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::process;
+
+const USAGE: &str = "usage: aoc-bits compile <expression> [--length-type <0|1>]
+       aoc-bits random [--seed <n>] [--depth <n>] [--length-type <0|1>]
+       aoc-bits print <hex>
+       aoc-bits dump <hex>
+       aoc-bits stream [--raw] [path|-]";
+
+enum Error {
+    Usage(String),
+    Input(String),
+}
+
+struct Args {
+    command: String,
+    operand: Option<String>,
+    length_type: LengthType,
+    seed: u64,
+    depth: usize,
+    format: Format,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut args = args.iter();
+    let command = args.next().ok_or("missing command")?.clone();
+    let operand = match command.as_str() {
+        "compile" | "print" | "dump" => Some(
+            args.next()
+                .ok_or_else(|| format!("{} requires an argument", command))?
+                .clone(),
+        ),
+        "random" | "stream" => None,
+        other => return Err(format!("unknown command: {}", other)),
+    };
+
+    let mut length_type = LengthType::Bits;
+    let mut seed = 1;
+    let mut depth = 4;
+    let mut format = Format::Hex;
+    let mut operand = operand;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--raw" if command == "stream" => format = Format::Raw,
+            path if command == "stream" && operand.is_none() && !path.starts_with("--") => {
+                operand = Some(path.to_owned())
+            }
+            "--length-type" if command == "compile" || command == "random" => {
+                length_type = match args.next().map(|s| s.as_str()) {
+                    Some("0") => LengthType::Bits,
+                    Some("1") => LengthType::Count,
+                    Some(other) => return Err(format!("invalid length type: {}", other)),
+                    None => return Err("--length-type requires a value".to_owned()),
+                }
+            }
+            "--seed" if command == "random" => {
+                let value = args.next().ok_or("--seed requires a value")?;
+                seed = value
+                    .parse()
+                    .map_err(|_| format!("invalid seed: {}", value))?;
+            }
+            "--depth" if command == "random" => {
+                let value = args.next().ok_or("--depth requires a value")?;
+                depth = match value.parse() {
+                    Ok(depth) if depth <= DEFAULT_MAX_DEPTH => depth,
+                    Ok(_) => return Err(format!("depth must be at most {}", DEFAULT_MAX_DEPTH)),
+                    Err(_) => return Err(format!("invalid depth: {}", value)),
+                };
+            }
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+
+    Ok(Args {
+        command,
+        operand,
+        length_type,
+        seed,
+        depth,
+        format,
+    })
+}
+
+fn run(args: &Args) -> Result<String, String> {
+    let operand = args.operand.as_deref().unwrap_or("");
+    let output = match args.command.as_str() {
+        "compile" => compile(operand)
+            .map_err(|err| err.diagnostic(operand))?
+            .to_hex(args.length_type)
+            .map_err(|err| err.to_string())?,
+        "random" => random_packet(&mut Rng::new(args.seed), args.depth)
+            .to_hex(args.length_type)
+            .map_err(|err| err.to_string())?,
+        "print" => Day16::parse(operand)
+            .map_err(|err| err.diagnostic(operand))?
+            .to_string(),
+        "dump" => {
+            let line = operand.trim();
+            let bytes = decode_hex(line).map_err(|err| err.diagnostic(line))?;
+            dump(&bytes).map_err(|err| err.at_digit(line).diagnostic(line))?
+        }
+        _ => unreachable!("commands are checked by parse_args"),
+    };
+
+    Ok(output)
+}
+
+/// Print one line per transmission with its expression and value, decoded
+/// as the input arrives.
+fn stream(path: Option<&str>, format: Format) -> Result<(), String> {
+    let reader: Box<dyn Read> = match path {
+        None | Some("-") => Box::new(io::stdin().lock()),
+        Some(path) => {
+            Box::new(File::open(path).map_err(|err| format!("could not read {}: {}", path, err))?)
+        }
+    };
+
+    let mut out = io::stdout().lock();
+    for packet in StreamDecoder::new(reader, format) {
+        let packet = packet.map_err(|err| err.to_string())?;
+        match packet.evaluate() {
+            Ok(value) => writeln!(out, "{} = {}", packet, value),
+            Err(err) => writeln!(out, "{} = {}", packet, err),
+        }
+        .map_err(|err| format!("could not write output: {}", err))?;
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match parse_args(&args) {
+        Ok(args) if args.command == "stream" => {
+            stream(args.operand.as_deref(), args.format).map_err(Error::Input)
+        }
+        Ok(args) => run(&args)
+            .map(|output| println!("{}", output.trim_end()))
+            .map_err(Error::Input),
+        Err(err) => Err(Error::Usage(err)),
+    };
+
+    match result {
+        Ok(()) => (),
+        Err(Error::Usage(err)) => {
+            eprintln!("error: {}\n{}", err, USAGE);
+            process::exit(2);
+        }
+        Err(Error::Input(err)) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{LiteralPacket, Operator, OperatorPacket, Packet, DEFAULT_MAX_DEPTH};
+use common::ParseError;
+use std::iter::Peekable;
+use std::slice::Iter;
+
+/// Compile an expression in the syntax packets are displayed in, such as
+/// `(min 7 (max 8 9))`, into a packet. Every packet gets version 0, and
+/// operators may be nested [`DEFAULT_MAX_DEPTH`] deep so the result decodes.
+pub fn compile(source: &str) -> Result<Packet, ParseError> {
+    let tokens = tokenize(source);
+    let mut tokens = tokens.iter().peekable();
+    let packet = expression(source, &mut tokens, 0)?;
+    match tokens.next() {
+        Some(token) => Err(error(source, token, "expected end of expression")),
+        None => Ok(packet),
+    }
+}
+
+/// Split into `(`, `)` and runs of anything else, skipping whitespace.
+fn tokenize(source: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (pos, chr) in source.char_indices() {
+        if chr.is_whitespace() || chr == '(' || chr == ')' {
+            if let Some(start) = start.take() {
+                tokens.push(&source[start..pos]);
+            }
+            if !chr.is_whitespace() {
+                tokens.push(&source[pos..pos + 1]);
+            }
+        } else if start.is_none() {
+            start = Some(pos);
+        }
+    }
+    if let Some(start) = start {
+        tokens.push(&source[start..]);
+    }
+
+    tokens
+}
+
+/// A [`ParseError`] pointing at `token`, which must be a slice of `source`.
+fn error(source: &str, token: &str, message: &str) -> ParseError {
+    let offset = token.as_ptr() as usize - source.as_ptr() as usize;
+    let line_idx = source[..offset].matches('\n').count();
+    let line = source.lines().nth(line_idx).unwrap_or("");
+    ParseError::at(line_idx, line, token, message)
+}
+
+/// Compile the next expression, inside `depth` enclosing operators.
+fn expression(
+    source: &str,
+    tokens: &mut Peekable<Iter<&str>>,
+    depth: usize,
+) -> Result<Packet, ParseError> {
+    let end = &source[source.len()..];
+    let token = *tokens
+        .next()
+        .ok_or_else(|| error(source, end, "expected an expression"))?;
+
+    match token {
+        "(" if depth == DEFAULT_MAX_DEPTH => {
+            return Err(error(source, token, "expressions nested too deeply"))
+        }
+        "(" => (),
+        ")" => return Err(error(source, token, "unexpected `)`")),
+        _ => {
+            let value = token.parse().map_err(|_| {
+                let message = if token.bytes().all(|b| b.is_ascii_digit()) {
+                    "literal out of range"
+                } else {
+                    "expected a number or `(`"
+                };
+                error(source, token, message)
+            })?;
+            return Ok(Packet::Literal(LiteralPacket { version: 0, value }));
+        }
+    }
+
+    let name = *tokens
+        .next()
+        .ok_or_else(|| error(source, end, "expected an operator"))?;
+    let operator =
+        Operator::from_name(name).ok_or_else(|| error(source, name, "unknown operator"))?;
+
+    let mut packets = Vec::new();
+    loop {
+        match tokens.peek() {
+            Some(&&")") => break,
+            Some(_) => packets.push(expression(source, tokens, depth + 1)?),
+            None => return Err(error(source, token, "unclosed `(`")),
+        }
+    }
+    tokens.next();
+
+    if !operator.accepts(packets.len()) {
+        let message = match operator {
+            Operator::Gt | Operator::Lt | Operator::Eq => "expected exactly two operands",
+            _ => "expected an operand",
+        };
+        return Err(error(source, name, message));
+    }
+
+    Ok(Packet::Operator(OperatorPacket {
+        version: 0,
+        operator,
+        packets,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{random_packet, Rng};
+    use crate::{decode, LengthType};
+
+    #[test]
+    fn test_compile() {
+        let packet = compile("(min 7 (max 8 9))").unwrap();
+        assert_eq!(packet.to_string(), "(min 7 (max 8 9))");
+        assert_eq!(packet.evaluate(), Ok(7));
+
+        let hex = packet.to_hex(LengthType::Bits).unwrap();
+        assert_eq!(hex, "0800DC4386002C220448");
+        assert_eq!(
+            compile("(sum\n  1\n  (product 2 3))").unwrap().to_string(),
+            "(sum 1 (product 2 3))"
+        );
+
+        let mut rng = Rng::new(16);
+        for _ in 0..100 {
+            let packet = random_packet(&mut rng, 4);
+            let compiled = compile(&packet.to_string()).unwrap();
+            assert_eq!(compiled.to_string(), packet.to_string());
+            assert_eq!(
+                decode(&compiled.encode(LengthType::Count).unwrap()),
+                Ok(compiled)
+            );
+        }
+    }
+
+    #[test]
+    fn test_compile_errors() {
+        let err = compile("(gt 1)").unwrap_err();
+        assert_eq!(
+            (err.column, err.message.as_str()),
+            (2, "expected exactly two operands")
+        );
+
+        let err = compile("(sum 1\n  (foo 2))").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "foo"));
+
+        let err = compile("(sum 1 (max 2)").unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (1, "unclosed `(`"));
+
+        let err = compile("1 2").unwrap_err();
+        assert_eq!(err.column, 3);
+
+        let err = compile("(sum x)").unwrap_err();
+        assert_eq!(err.message, "expected a number or `(`");
+
+        let err = compile("(sum 18446744073709551616)").unwrap_err();
+        assert_eq!(
+            (err.column, err.text.as_str(), err.message.as_str()),
+            (6, "18446744073709551616", "literal out of range")
+        );
+
+        let nested = |depth| "(sum ".repeat(depth) + "1" + &")".repeat(depth);
+        let packet = compile(&nested(DEFAULT_MAX_DEPTH)).unwrap();
+        assert!(decode(&packet.encode(LengthType::Bits).unwrap()).is_ok());
+        let err = compile(&nested(DEFAULT_MAX_DEPTH + 1)).unwrap_err();
+        assert_eq!(
+            (err.column, err.message.as_str()),
+            (5 * DEFAULT_MAX_DEPTH + 1, "expressions nested too deeply")
+        );
+    }
+}
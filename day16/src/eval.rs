@@ -0,0 +1,125 @@
+use crate::{Operator, Packet};
+use common::Answer;
+#[cfg(feature = "bigint")]
+use num_bigint::BigUint;
+use std::error::Error;
+use std::fmt;
+
+/// An operator whose result doesn't fit in a `u64`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OverflowError {
+    pub operator: Operator,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` overflowed a 64-bit value", self.operator.name())
+    }
+}
+
+impl Error for OverflowError {}
+
+/// The value of a transmission as reported for part 2, which without the
+/// `bigint` feature may be an overflow instead. The runners report an
+/// overflow as a failed part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Evaluation(pub Result<u64, OverflowError>);
+
+impl Answer for Evaluation {
+    fn to_answer(&self) -> Result<String, String> {
+        match &self.0 {
+            Ok(value) => Ok(value.to_string()),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+}
+
+impl Packet {
+    /// The value of the expression, or an error if any intermediate result
+    /// overflows a `u64`.
+    pub fn evaluate(&self) -> Result<u64, OverflowError> {
+        let packet = match self {
+            Packet::Literal(packet) => return Ok(packet.value),
+            Packet::Operator(packet) => packet,
+        };
+
+        let values = packet
+            .packets
+            .iter()
+            .map(Packet::evaluate)
+            .collect::<Result<Vec<_>, _>>()?;
+        let overflow = OverflowError {
+            operator: packet.operator,
+        };
+
+        let value = match packet.operator {
+            Operator::Sum => values
+                .iter()
+                .try_fold(0u64, |sum, &value| sum.checked_add(value))
+                .ok_or(overflow)?,
+            Operator::Product => values
+                .iter()
+                .try_fold(1u64, |product, &value| product.checked_mul(value))
+                .ok_or(overflow)?,
+            Operator::Min => *values.iter().min().unwrap(),
+            Operator::Max => *values.iter().max().unwrap(),
+            Operator::Gt => (values[0] > values[1]) as u64,
+            Operator::Lt => (values[0] < values[1]) as u64,
+            Operator::Eq => (values[0] == values[1]) as u64,
+        };
+
+        Ok(value)
+    }
+
+    /// The value of the expression in arbitrary precision. Only the
+    /// arithmetic is unbounded: literals are still decoded as `u64`, and a
+    /// longer one is rejected with [`BitsError::LiteralOverflow`].
+    ///
+    /// [`BitsError::LiteralOverflow`]: crate::BitsError::LiteralOverflow
+    #[cfg(feature = "bigint")]
+    pub fn evaluate_big(&self) -> BigUint {
+        let packet = match self {
+            Packet::Literal(packet) => return BigUint::from(packet.value),
+            Packet::Operator(packet) => packet,
+        };
+
+        let values: Vec<BigUint> = packet.packets.iter().map(Packet::evaluate_big).collect();
+        match packet.operator {
+            Operator::Sum => values.into_iter().sum(),
+            Operator::Product => values.into_iter().product(),
+            Operator::Min => values.into_iter().min().unwrap(),
+            Operator::Max => values.into_iter().max().unwrap(),
+            Operator::Gt => BigUint::from((values[0] > values[1]) as u8),
+            Operator::Lt => BigUint::from((values[0] < values[1]) as u8),
+            Operator::Eq => BigUint::from((values[0] == values[1]) as u8),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{compile, Operator};
+
+    #[test]
+    fn test_evaluate() {
+        let packet = compile("(sum 1 (product 2 3) (gt 5 15))").unwrap();
+        assert_eq!(packet.evaluate(), Ok(7));
+
+        let packet = compile("(min 4 (product 4294967296 4294967296))").unwrap();
+        let err = packet.evaluate().unwrap_err();
+        assert_eq!(err.operator, Operator::Product);
+
+        let packet = compile("(sum 18446744073709551615 1)").unwrap();
+        assert!(packet.evaluate().is_err());
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_evaluate_big() {
+        let packet = compile("(product 4294967296 4294967296 10)").unwrap();
+        assert_eq!(packet.evaluate_big().to_string(), "184467440737095516160");
+
+        let packet = compile("(gt (sum 18446744073709551615 1) 18446744073709551615)").unwrap();
+        assert_eq!(packet.evaluate_big().to_string(), "1");
+    }
+}
@@ -0,0 +1,875 @@
+use common::{ParseError, Solution};
+#[cfg(feature = "bigint")]
+use num_bigint::BigUint;
+use std::error::Error;
+use std::fmt;
+
+mod compile;
+mod eval;
+mod print;
+pub mod random;
+mod stream;
+
+pub use compile::compile;
+pub use eval::{Evaluation, OverflowError};
+pub use print::{annotate, dump, Annotation};
+pub use stream::{Format, StreamDecoder, StreamError};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Packet {
+    Literal(LiteralPacket),
+    Operator(OperatorPacket),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LiteralPacket {
+    pub version: u8,
+    pub value: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OperatorPacket {
+    pub version: u8,
+    pub operator: Operator,
+    pub packets: Vec<Packet>,
+}
+
+/// The operation an operator packet performs on its sub-packets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    Sum,
+    Product,
+    Min,
+    Max,
+    Gt,
+    Lt,
+    Eq,
+}
+
+impl Operator {
+    pub const ALL: [Operator; 7] = [
+        Operator::Sum,
+        Operator::Product,
+        Operator::Min,
+        Operator::Max,
+        Operator::Gt,
+        Operator::Lt,
+        Operator::Eq,
+    ];
+
+    /// The operator for a packet type ID, or `None` for literals (4) and
+    /// anything that doesn't fit in three bits.
+    pub fn from_type_id(type_id: u8) -> Option<Operator> {
+        match type_id {
+            0 => Some(Operator::Sum),
+            1 => Some(Operator::Product),
+            2 => Some(Operator::Min),
+            3 => Some(Operator::Max),
+            5 => Some(Operator::Gt),
+            6 => Some(Operator::Lt),
+            7 => Some(Operator::Eq),
+            _ => None,
+        }
+    }
+
+    pub fn type_id(self) -> u8 {
+        match self {
+            Operator::Sum => 0,
+            Operator::Product => 1,
+            Operator::Min => 2,
+            Operator::Max => 3,
+            Operator::Gt => 5,
+            Operator::Lt => 6,
+            Operator::Eq => 7,
+        }
+    }
+
+    /// Name used in printed and compiled expressions.
+    pub fn name(self) -> &'static str {
+        match self {
+            Operator::Sum => "sum",
+            Operator::Product => "product",
+            Operator::Min => "min",
+            Operator::Max => "max",
+            Operator::Gt => "gt",
+            Operator::Lt => "lt",
+            Operator::Eq => "eq",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Operator> {
+        Operator::ALL.into_iter().find(|op| op.name() == name)
+    }
+
+    /// Comparisons take exactly two operands, everything else at least one.
+    pub fn accepts(self, count: usize) -> bool {
+        match self {
+            Operator::Gt | Operator::Lt | Operator::Eq => count == 2,
+            _ => count >= 1,
+        }
+    }
+}
+
+/// How an operator packet says where its sub-packets end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LengthType {
+    /// Type 0: a 15-bit total length in bits.
+    Bits,
+    /// Type 1: an 11-bit number of sub-packets.
+    Count,
+}
+
+impl LengthType {
+    /// Width of the length field that follows the length type bit.
+    fn field_bits(self) -> usize {
+        match self {
+            LengthType::Bits => 15,
+            LengthType::Count => 11,
+        }
+    }
+
+    fn other(self) -> LengthType {
+        match self {
+            LengthType::Bits => LengthType::Count,
+            LengthType::Count => LengthType::Bits,
+        }
+    }
+
+    /// Whether sub-packets taking `bits` bits, `count` of them, fit in the
+    /// length field.
+    fn fits(self, bits: usize, count: usize) -> bool {
+        match self {
+            LengthType::Bits => bits < 1 << 15,
+            LengthType::Count => count < 1 << 11,
+        }
+    }
+}
+
+/// An operator packet whose sub-packets fit in neither length field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EncodeError {
+    pub bits: usize,
+    pub count: usize,
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "operator with {} sub-packets of {} bits is too large to encode",
+            self.count, self.bits
+        )
+    }
+}
+
+impl Error for EncodeError {}
+
+impl Packet {
+    /// Encode as a transmission, zero-padded to a whole number of bytes.
+    /// Operator packets use `length_type` unless their sub-packets don't fit
+    /// in its length field, in which case they use the other one.
+    pub fn encode(&self, length_type: LengthType) -> Result<Vec<u8>, EncodeError> {
+        let mut writer = Writer::new();
+        self.write(&mut writer, length_type)?;
+        Ok(writer.into_bytes())
+    }
+
+    /// [`Packet::encode`] as upper-case hex, the puzzle input format.
+    pub fn to_hex(&self, length_type: LengthType) -> Result<String, EncodeError> {
+        Ok(self
+            .encode(length_type)?
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect())
+    }
+
+    fn write(&self, writer: &mut Writer, length_type: LengthType) -> Result<(), EncodeError> {
+        match self {
+            Packet::Literal(packet) => {
+                writer.write(packet.version as u64, 3);
+                writer.write(4, 3);
+                for chunk in (0..literal_chunks(packet.value)).rev() {
+                    let more_chunks = if chunk > 0 { 0b10000 } else { 0 };
+                    writer.write(more_chunks | (packet.value >> (4 * chunk) & 0xf), 5);
+                }
+            }
+            Packet::Operator(packet) => {
+                let mut sub_packets = Writer::new();
+                for packet in &packet.packets {
+                    packet.write(&mut sub_packets, length_type)?;
+                }
+                let bits = sub_packets.bit_pos;
+                let count = packet.packets.len();
+                let chosen = [length_type, length_type.other()]
+                    .into_iter()
+                    .find(|length_type| length_type.fits(bits, count))
+                    .ok_or(EncodeError { bits, count })?;
+
+                writer.write(packet.version as u64, 3);
+                writer.write(packet.operator.type_id() as u64, 3);
+                match chosen {
+                    LengthType::Bits => {
+                        writer.write(0, 1);
+                        writer.write(bits as u64, 15);
+                    }
+                    LengthType::Count => {
+                        writer.write(1, 1);
+                        writer.write(count as u64, 11);
+                    }
+                }
+                writer.append(&sub_packets);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Nesting depth [`decode`] allows before giving up.
+pub const DEFAULT_MAX_DEPTH: usize = 64;
+
+/// Why a transmission could not be decoded. Positions are bit offsets of the
+/// start of the offending read or packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BitsError {
+    UnexpectedEof {
+        bit_pos: usize,
+    },
+    InvalidTypeId {
+        bit_pos: usize,
+        type_id: u8,
+    },
+    BadOperandCount {
+        bit_pos: usize,
+        operator: Operator,
+        count: usize,
+    },
+    LengthMismatch {
+        bit_pos: usize,
+        expected: usize,
+        actual: usize,
+    },
+    LiteralOverflow {
+        bit_pos: usize,
+    },
+    TooDeep {
+        bit_pos: usize,
+        max_depth: usize,
+    },
+}
+
+impl BitsError {
+    pub fn bit_pos(&self) -> usize {
+        match *self {
+            BitsError::UnexpectedEof { bit_pos }
+            | BitsError::InvalidTypeId { bit_pos, .. }
+            | BitsError::BadOperandCount { bit_pos, .. }
+            | BitsError::LengthMismatch { bit_pos, .. }
+            | BitsError::LiteralOverflow { bit_pos }
+            | BitsError::TooDeep { bit_pos, .. } => bit_pos,
+        }
+    }
+
+    /// A [`ParseError`] pointing at the hex digit of `line` holding the
+    /// error's bit, or at the end of the line past the last digit.
+    pub fn at_digit(&self, line: &str) -> ParseError {
+        let digit = self.bit_pos() / 4;
+        match line.get(digit..digit + 1) {
+            Some(text) => ParseError::at(0, line, text, self.to_string()),
+            None => ParseError::at_end(0, line, self.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for BitsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BitsError::UnexpectedEof { bit_pos } => {
+                write!(f, "unexpected end of transmission at bit {}", bit_pos)
+            }
+            BitsError::InvalidTypeId { bit_pos, type_id } => {
+                write!(f, "invalid type id {} at bit {}", type_id, bit_pos)
+            }
+            BitsError::BadOperandCount {
+                bit_pos,
+                operator,
+                count,
+            } => write!(
+                f,
+                "`{}` operator at bit {} has {} sub-packets",
+                operator.name(),
+                bit_pos,
+                count
+            ),
+            BitsError::LengthMismatch {
+                bit_pos,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "sub-packets of operator at bit {} take {} bits, expected {}",
+                bit_pos, actual, expected
+            ),
+            BitsError::LiteralOverflow { bit_pos } => {
+                write!(f, "literal at bit {} does not fit in 64 bits", bit_pos)
+            }
+            BitsError::TooDeep { bit_pos, max_depth } => write!(
+                f,
+                "packet at bit {} is nested more than {} deep",
+                bit_pos, max_depth
+            ),
+        }
+    }
+}
+
+impl Error for BitsError {}
+
+/// Decode the first packet of a transmission, ignoring any padding after it.
+pub fn decode(data: &[u8]) -> Result<Packet, BitsError> {
+    decode_with_max_depth(data, DEFAULT_MAX_DEPTH)
+}
+
+/// [`decode`] with operators nested at most `max_depth` deep.
+pub fn decode_with_max_depth(data: &[u8], max_depth: usize) -> Result<Packet, BitsError> {
+    parse(&mut Cursor::new(data), 0, max_depth, None)
+}
+
+/// Number of 4-bit groups needed for a literal value.
+fn literal_chunks(value: u64) -> usize {
+    (64 - value.leading_zeros() as usize).div_ceil(4).max(1)
+}
+
+/// A source of bits for the decoder, read most significant bit first.
+trait BitRead {
+    type Error: From<BitsError>;
+
+    /// Bits read since the start of the transmission.
+    fn bit_pos(&self) -> usize;
+
+    fn read(&mut self, num_bits: usize) -> Result<u64, Self::Error>;
+}
+
+struct Cursor<'a> {
+    bit_pos: usize,
+    data: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn new(data: &'a [u8]) -> Cursor<'a> {
+        Self { bit_pos: 0, data }
+    }
+}
+
+impl BitRead for Cursor<'_> {
+    type Error = BitsError;
+
+    fn bit_pos(&self) -> usize {
+        self.bit_pos
+    }
+
+    fn read(&mut self, num_bits: usize) -> Result<u64, BitsError> {
+        if self.bit_pos + num_bits > self.data.len() * 8 {
+            return Err(BitsError::UnexpectedEof {
+                bit_pos: self.bit_pos,
+            });
+        }
+
+        let mut retval = 0;
+        let mut bits_to_get = num_bits;
+        let mut byte_pos = self.bit_pos / 8;
+
+        while bits_to_get > 0 {
+            let remaining_bits_in_byte = 8 - self.bit_pos % 8;
+            let bits_grabbed = remaining_bits_in_byte.min(bits_to_get);
+            let mask = 0xff >> (8 - bits_grabbed) << (remaining_bits_in_byte - bits_grabbed);
+
+            bits_to_get -= bits_grabbed;
+            retval |= ((self.data[byte_pos] & mask) as u64)
+                >> (8 - bits_grabbed - self.bit_pos % 8)
+                << bits_to_get;
+            self.bit_pos += bits_grabbed;
+            if self.bit_pos.is_multiple_of(8) {
+                byte_pos += 1;
+            }
+        }
+
+        Ok(retval)
+    }
+}
+
+struct Writer {
+    bit_pos: usize,
+    data: Vec<u8>,
+}
+
+impl Writer {
+    fn new() -> Writer {
+        Self {
+            bit_pos: 0,
+            data: Vec::new(),
+        }
+    }
+
+    /// Append the low `num_bits` bits of `value`, most significant first.
+    fn write(&mut self, value: u64, num_bits: usize) {
+        let mut bits_to_put = num_bits;
+
+        while bits_to_put > 0 {
+            if self.bit_pos.is_multiple_of(8) {
+                self.data.push(0);
+            }
+            let remaining_bits_in_byte = 8 - self.bit_pos % 8;
+            let bits_put = remaining_bits_in_byte.min(bits_to_put);
+
+            bits_to_put -= bits_put;
+            let bits = (value >> bits_to_put) & (0xff >> (8 - bits_put));
+            *self.data.last_mut().unwrap() |= (bits as u8) << (remaining_bits_in_byte - bits_put);
+            self.bit_pos += bits_put;
+        }
+    }
+
+    /// Append everything written to `other`.
+    fn append(&mut self, other: &Writer) {
+        for (i, &byte) in other.data.iter().enumerate() {
+            let num_bits = (other.bit_pos - 8 * i).min(8);
+            self.write((byte >> (8 - num_bits)) as u64, num_bits);
+        }
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+/// Parse a packet inside `depth` enclosing operators, recording each packet
+/// in `trace` in pre-order if given.
+fn parse<B: BitRead>(
+    cursor: &mut B,
+    depth: usize,
+    max_depth: usize,
+    mut trace: Option<&mut Vec<Annotation>>,
+) -> Result<Packet, B::Error> {
+    let start = cursor.bit_pos();
+    let version = cursor.read(3)? as u8;
+    let type_id = cursor.read(3)? as u8;
+
+    if type_id == 4 {
+        let mut value: u64 = 0;
+        loop {
+            let chunk = cursor.read(5)?;
+            if value >> 60 != 0 {
+                return Err(BitsError::LiteralOverflow { bit_pos: start }.into());
+            }
+            let more_chunks = chunk & 0b10000 > 0;
+            value = (value << 4) | (chunk & 0xf);
+            if !more_chunks {
+                break;
+            }
+        }
+        if let Some(trace) = trace {
+            trace.push(Annotation {
+                bit_pos: start,
+                depth,
+                version,
+                type_id,
+                length: None,
+            });
+        }
+        return Ok(Packet::Literal(LiteralPacket { version, value }));
+    }
+
+    let operator = Operator::from_type_id(type_id).ok_or(BitsError::InvalidTypeId {
+        bit_pos: start,
+        type_id,
+    })?;
+    if depth >= max_depth {
+        return Err(BitsError::TooDeep {
+            bit_pos: start,
+            max_depth,
+        }
+        .into());
+    }
+
+    let mut sub_packets = Vec::new();
+    let length_type = if cursor.read(1)? > 0 {
+        LengthType::Count
+    } else {
+        LengthType::Bits
+    };
+    let length = cursor.read(length_type.field_bits())? as usize;
+    if let Some(trace) = trace.as_deref_mut() {
+        trace.push(Annotation {
+            bit_pos: start,
+            depth,
+            version,
+            type_id,
+            length: Some((length_type, length)),
+        });
+    }
+
+    match length_type {
+        LengthType::Count => {
+            for _ in 0..length {
+                sub_packets.push(parse(cursor, depth + 1, max_depth, trace.as_deref_mut())?);
+            }
+        }
+        LengthType::Bits => {
+            let target_pos = cursor.bit_pos() + length;
+            while cursor.bit_pos() < target_pos {
+                sub_packets.push(parse(cursor, depth + 1, max_depth, trace.as_deref_mut())?);
+            }
+            if cursor.bit_pos() != target_pos {
+                return Err(BitsError::LengthMismatch {
+                    bit_pos: start,
+                    expected: length,
+                    actual: length + cursor.bit_pos() - target_pos,
+                }
+                .into());
+            }
+        }
+    }
+
+    if !operator.accepts(sub_packets.len()) {
+        return Err(BitsError::BadOperandCount {
+            bit_pos: start,
+            operator,
+            count: sub_packets.len(),
+        }
+        .into());
+    }
+
+    Ok(Packet::Operator(OperatorPacket {
+        version,
+        operator,
+        packets: sub_packets,
+    }))
+}
+
+fn solve1(packet: &Packet) -> u64 {
+    let mut sum = 0;
+
+    match packet {
+        Packet::Literal(packet) => sum += packet.version as u64,
+        Packet::Operator(packet) => {
+            sum += packet.version as u64;
+            for packet in &packet.packets {
+                sum += solve1(packet);
+            }
+        }
+    }
+
+    sum
+}
+
+#[cfg(not(feature = "bigint"))]
+fn solve2(packet: &Packet) -> Evaluation {
+    Evaluation(packet.evaluate())
+}
+
+#[cfg(feature = "bigint")]
+fn solve2(packet: &Packet) -> BigUint {
+    packet.evaluate_big()
+}
+
+/// Bytes of a hex transmission; an odd trailing digit fills the high nibble.
+pub fn decode_hex(line: &str) -> Result<Vec<u8>, ParseError> {
+    let mut bytes = Vec::with_capacity(line.len().div_ceil(2));
+    for (i, (pos, chr)) in line.char_indices().enumerate() {
+        let nibble = chr.to_digit(16).ok_or_else(|| {
+            ParseError::at(
+                0,
+                line,
+                &line[pos..pos + chr.len_utf8()],
+                "expected a hex digit",
+            )
+        })? as u8;
+        if i % 2 == 0 {
+            bytes.push(nibble << 4);
+        } else {
+            *bytes.last_mut().unwrap() |= nibble;
+        }
+    }
+
+    Ok(bytes)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input<'a> = Packet;
+    type Answer1 = u64;
+    #[cfg(not(feature = "bigint"))]
+    type Answer2 = Evaluation;
+    #[cfg(feature = "bigint")]
+    type Answer2 = BigUint;
+
+    fn parse(input: &str) -> Result<Packet, ParseError> {
+        let line = input.trim();
+        let input_bytes = decode_hex(line)?;
+
+        decode(&input_bytes).map_err(|err| err.at_digit(line))
+    }
+
+    fn solve1(input: &Packet) -> u64 {
+        solve1(input)
+    }
+
+    fn solve2(input: &Packet) -> Self::Answer2 {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{random_packet, Rng};
+
+    const INPUT1: &[u8] = &[
+        0xA0, 0x01, 0x6C, 0x88, 0x01, 0x62, 0x01, 0x7C, 0x36, 0x86, 0xB1, 0x8A, 0x3D, 0x47, 0x80,
+    ];
+    const INPUT2: &[u8] = &[0x8A, 0x00, 0x4A, 0x80, 0x1A, 0x80, 0x02, 0xF4, 0x78];
+
+    #[test]
+    fn test_cursor() {
+        let mut cursor = Cursor::new(&[0xD2, 0xFE, 0x28]);
+        let version = cursor.read(3);
+        assert_eq!(version, Ok(6));
+        assert_eq!(cursor.bit_pos, 3);
+        let type_id = cursor.read(3);
+        assert_eq!(cursor.bit_pos, 6);
+        assert_eq!(type_id, Ok(4));
+        assert_eq!(cursor.read(5), Ok(0b10111));
+        assert_eq!(cursor.read(5), Ok(0b11110));
+        assert_eq!(cursor.read(5), Ok(0b00101));
+        assert_eq!(
+            cursor.read(4),
+            Err(BitsError::UnexpectedEof { bit_pos: 21 })
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let parsed = decode(&[0xD2, 0xFE, 0x28]).unwrap();
+        if let Packet::Literal(packet) = parsed {
+            assert_eq!(packet.version, 6);
+            assert_eq!(packet.value, 2021);
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn test_operator() {
+        for operator in Operator::ALL {
+            assert_eq!(Operator::from_type_id(operator.type_id()), Some(operator));
+            assert_eq!(Operator::from_name(operator.name()), Some(operator));
+        }
+        assert_eq!(Operator::from_type_id(4), None);
+        assert_eq!(Operator::from_type_id(8), None);
+        assert!(Operator::Eq.accepts(2) && !Operator::Eq.accepts(3));
+        assert!(Operator::Sum.accepts(3) && !Operator::Sum.accepts(0));
+    }
+
+    #[test]
+    fn test_writer() {
+        let mut writer = Writer::new();
+        writer.write(6, 3);
+        writer.write(4, 3);
+        writer.write(0b10111, 5);
+        writer.write(0b11110, 5);
+        writer.write(0b00101, 5);
+        assert_eq!(writer.bit_pos, 21);
+        assert_eq!(writer.into_bytes(), vec![0xD2, 0xFE, 0x28]);
+    }
+
+    #[test]
+    fn test_encode() {
+        let literal = |version, value| Packet::Literal(LiteralPacket { version, value });
+        let packet = Packet::Literal(LiteralPacket {
+            version: 6,
+            value: 2021,
+        });
+        assert_eq!(packet.to_hex(LengthType::Bits).unwrap(), "D2FE28");
+
+        let packet = Packet::Operator(OperatorPacket {
+            version: 1,
+            operator: Operator::Lt,
+            packets: vec![literal(6, 10), literal(2, 20)],
+        });
+        assert_eq!(
+            packet.encode(LengthType::Bits).unwrap(),
+            vec![0x38, 0x00, 0x6F, 0x45, 0x29, 0x12, 0x00]
+        );
+
+        let packet = Packet::Operator(OperatorPacket {
+            version: 7,
+            operator: Operator::Max,
+            packets: vec![literal(2, 1), literal(4, 2), literal(1, 3)],
+        });
+        assert_eq!(packet.to_hex(LengthType::Count).unwrap(), "EE00D40C823060");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        for _ in 0..500 {
+            let packet = random_packet(&mut rng, 4);
+            for length_type in [LengthType::Bits, LengthType::Count] {
+                let bytes = packet.encode(length_type).unwrap();
+                assert_eq!(decode(&bytes), Ok(packet.clone()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_large() {
+        let sum = |packets| {
+            Packet::Operator(OperatorPacket {
+                version: 0,
+                operator: Operator::Sum,
+                packets,
+            })
+        };
+        let literal = |value| Packet::Literal(LiteralPacket { version: 0, value });
+
+        // 400 literals of 86 bits overflow the 15-bit length field, and 2100
+        // literals overflow the 11-bit count.
+        let wide = sum(vec![
+            sum(vec![literal(u64::MAX); 400]),
+            sum(vec![literal(1); 2100]),
+        ]);
+        for length_type in [LengthType::Bits, LengthType::Count] {
+            let bytes = wide.encode(length_type).unwrap();
+            assert_eq!(decode(&bytes), Ok(wide.clone()));
+        }
+
+        assert_eq!(
+            sum(vec![literal(1); 3000]).encode(LengthType::Bits),
+            Err(EncodeError {
+                bits: 33000,
+                count: 3000
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            decode(&[0xD2, 0xFE]),
+            Err(BitsError::UnexpectedEof { bit_pos: 16 })
+        );
+
+        let literal = || {
+            Packet::Literal(LiteralPacket {
+                version: 0,
+                value: 1,
+            })
+        };
+        let packet = Packet::Operator(OperatorPacket {
+            version: 0,
+            operator: Operator::Gt,
+            packets: vec![literal(), literal(), literal()],
+        });
+        assert_eq!(
+            decode(&packet.encode(LengthType::Count).unwrap()),
+            Err(BitsError::BadOperandCount {
+                bit_pos: 0,
+                operator: Operator::Gt,
+                count: 3,
+            })
+        );
+
+        // A sum claiming 10 bits of sub-packets followed by an 11-bit literal.
+        let mut writer = Writer::new();
+        writer.write(0, 3);
+        writer.write(0, 3);
+        writer.write(0, 1);
+        writer.write(10, 15);
+        writer.write(0, 3);
+        writer.write(4, 3);
+        writer.write(0b00001, 5);
+        assert_eq!(
+            decode(&writer.into_bytes()),
+            Err(BitsError::LengthMismatch {
+                bit_pos: 0,
+                expected: 10,
+                actual: 11,
+            })
+        );
+
+        let mut writer = Writer::new();
+        writer.write(0, 3);
+        writer.write(4, 3);
+        for _ in 0..16 {
+            writer.write(0b11111, 5);
+        }
+        writer.write(0b00001, 5);
+        assert_eq!(
+            decode(&writer.into_bytes()),
+            Err(BitsError::LiteralOverflow { bit_pos: 0 })
+        );
+
+        let mut packet = literal();
+        for _ in 0..3 {
+            packet = Packet::Operator(OperatorPacket {
+                version: 0,
+                operator: Operator::Sum,
+                packets: vec![packet],
+            });
+        }
+        let bytes = packet.encode(LengthType::Bits).unwrap();
+        assert!(decode_with_max_depth(&bytes, 3).is_ok());
+        assert_eq!(
+            decode_with_max_depth(&bytes, 2),
+            Err(BitsError::TooDeep {
+                bit_pos: 44,
+                max_depth: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day16::parse("D2FE").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+        assert_eq!(err.message, "unexpected end of transmission at bit 16");
+
+        let err = decode(&[0xE0]).unwrap_err().at_digit("E0");
+        assert_eq!((err.line, err.column), (1, 2));
+    }
+
+    #[test]
+    fn test_solve1() {
+        let parsed = decode(INPUT1).unwrap();
+        assert_eq!(solve1(&parsed), 31);
+
+        let parsed = decode(INPUT2).unwrap();
+        assert_eq!(solve1(&parsed), 16);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let parsed = decode(&[
+            0x9C, 0x01, 0x41, 0x08, 0x02, 0x50, 0x32, 0x0F, 0x18, 0x02, 0x10, 0x4A, 0x08,
+        ])
+        .unwrap();
+        assert_eq!(parsed.evaluate(), Ok(1));
+    }
+
+    #[cfg(not(feature = "bigint"))]
+    #[test]
+    fn test_solve2_overflow() {
+        use common::Answer;
+
+        let packet = compile("(sum 1 (product 4294967296 4294967296))").unwrap();
+        let parsed = Day16::parse(&packet.to_hex(LengthType::Bits).unwrap()).unwrap();
+        assert_eq!(
+            Day16::solve2(&parsed).to_answer(),
+            Err("`product` overflowed a 64-bit value".to_owned())
+        );
+        let parsed = Day16::parse("C200B40A82").unwrap();
+        assert_eq!(Day16::solve2(&parsed).to_answer(), Ok("3".to_owned()));
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{parse, BitsError, Cursor, LengthType, Operator, Packet, DEFAULT_MAX_DEPTH};
+use std::fmt;
+
+/// Where and how one packet was encoded in a transmission.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Annotation {
+    pub bit_pos: usize,
+    /// Number of enclosing operators.
+    pub depth: usize,
+    pub version: u8,
+    pub type_id: u8,
+    /// Length type and length field of operator packets.
+    pub length: Option<(LengthType, usize)>,
+}
+
+fn type_name(type_id: u8) -> &'static str {
+    match Operator::from_type_id(type_id) {
+        Some(operator) => operator.name(),
+        None => "literal",
+    }
+}
+
+/// S-expression form, e.g. `(sum 1 (product 2 3) (gt 5 15))`.
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Literal(packet) => write!(f, "{}", packet.value),
+            Packet::Operator(packet) => {
+                write!(f, "({}", packet.operator.name())?;
+                for packet in &packet.packets {
+                    write!(f, " {}", packet)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// Decode a transmission, returning an annotation for every packet in
+/// pre-order alongside the packet itself.
+pub fn annotate(data: &[u8]) -> Result<(Packet, Vec<Annotation>), BitsError> {
+    let mut trace = Vec::new();
+    let packet = parse(
+        &mut Cursor::new(data),
+        0,
+        DEFAULT_MAX_DEPTH,
+        Some(&mut trace),
+    )?;
+    Ok((packet, trace))
+}
+
+fn preorder<'a>(packet: &'a Packet, packets: &mut Vec<&'a Packet>) {
+    packets.push(packet);
+    if let Packet::Operator(packet) = packet {
+        for packet in &packet.packets {
+            preorder(packet, packets);
+        }
+    }
+}
+
+/// One line per packet with its bit offset, version, type, length type and
+/// the value it evaluates to, indented by nesting depth.
+pub fn dump(data: &[u8]) -> Result<String, BitsError> {
+    let (packet, trace) = annotate(data)?;
+    let mut packets = Vec::new();
+    preorder(&packet, &mut packets);
+
+    let mut output = String::from("  bit  packet\n");
+    for (annotation, packet) in trace.iter().zip(packets) {
+        output.push_str(&format!(
+            "{:>5}  {}v{} {}",
+            annotation.bit_pos,
+            "  ".repeat(annotation.depth),
+            annotation.version,
+            type_name(annotation.type_id)
+        ));
+        match annotation.length {
+            Some((LengthType::Bits, length)) => {
+                output.push_str(&format!(", length type 0 ({} bits)", length))
+            }
+            Some((LengthType::Count, length)) => {
+                output.push_str(&format!(", length type 1 ({} packets)", length))
+            }
+            None => (),
+        }
+        match packet.evaluate() {
+            Ok(value) => output.push_str(&format!(" = {}\n", value)),
+            Err(_) => output.push_str(" = overflow\n"),
+        }
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode;
+
+    // 1 + 3 == 2 * 2
+    const INPUT: &[u8] = &[
+        0x9C, 0x01, 0x41, 0x08, 0x02, 0x50, 0x32, 0x0F, 0x18, 0x02, 0x10, 0x4A, 0x08,
+    ];
+
+    #[test]
+    fn test_display() {
+        let packet = decode(INPUT).unwrap();
+        assert_eq!(packet.to_string(), "(eq (sum 1 3) (product 2 2))");
+    }
+
+    #[test]
+    fn test_dump() {
+        assert_eq!(
+            dump(INPUT).unwrap(),
+            "  bit  packet
+    0  v4 eq, length type 0 (80 bits) = 1
+   22    v2 sum, length type 1 (2 packets) = 4
+   40      v2 literal = 1
+   51      v4 literal = 3
+   62    v6 product, length type 1 (2 packets) = 4
+   80      v0 literal = 2
+   91      v2 literal = 2
+"
+        );
+    }
+}
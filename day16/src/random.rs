@@ -0,0 +1,51 @@
+use crate::{LiteralPacket, Operator, OperatorPacket, Packet};
+
+/// Xorshift generator, so random transmissions are reproducible without
+/// pulling in a random number crate.
+pub struct Rng(u64);
+
+impl Rng {
+    /// A generator from `seed`; zero is replaced since xorshift would only
+    /// ever produce zeros from it.
+    pub fn new(seed: u64) -> Self {
+        Self(if seed == 0 {
+            0x2545_f491_4f6c_dd1d
+        } else {
+            seed
+        })
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Roughly uniform in `0..n`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+}
+
+/// A random packet with operators nested at most `depth` deep. Comparison
+/// operators always get exactly two sub-packets, so the result decodes.
+pub fn random_packet(rng: &mut Rng, depth: usize) -> Packet {
+    let version = rng.below(8) as u8;
+    if depth == 0 || rng.below(3) == 0 {
+        let value = rng.next_u64() >> rng.below(64);
+        return Packet::Literal(LiteralPacket { version, value });
+    }
+
+    let operator = Operator::ALL[rng.below(7) as usize];
+    let count = if operator.accepts(1) {
+        1 + rng.below(4)
+    } else {
+        2
+    };
+    Packet::Operator(OperatorPacket {
+        version,
+        operator,
+        packets: (0..count).map(|_| random_packet(rng, depth - 1)).collect(),
+    })
+}
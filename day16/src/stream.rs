@@ -0,0 +1,396 @@
+use crate::{parse, BitRead, BitsError, Packet, DEFAULT_MAX_DEPTH};
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read};
+
+/// How transmissions are laid out in a stream.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Hex digits, one transmission per line.
+    Hex,
+    /// Raw bytes, each transmission padded to a whole byte.
+    Raw,
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    InvalidHex {
+        transmission: usize,
+        bit_pos: usize,
+        byte: u8,
+    },
+    /// Set bits after the packet on its hex line.
+    TrailingData {
+        transmission: usize,
+        bit_pos: usize,
+    },
+    Bits {
+        transmission: usize,
+        error: BitsError,
+    },
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "could not read transmission: {}", err),
+            StreamError::InvalidHex {
+                transmission,
+                bit_pos,
+                byte,
+            } => write!(
+                f,
+                "transmission {}: expected a hex digit at bit {}, found {:?}",
+                transmission,
+                bit_pos,
+                char::from(*byte)
+            ),
+            StreamError::TrailingData {
+                transmission,
+                bit_pos,
+            } => write!(
+                f,
+                "transmission {}: unexpected data after the packet at bit {}",
+                transmission, bit_pos
+            ),
+            StreamError::Bits {
+                transmission,
+                error,
+            } => write!(f, "transmission {}: {}", transmission, error),
+        }
+    }
+}
+
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Io(err) => Some(err),
+            StreamError::InvalidHex { .. } | StreamError::TrailingData { .. } => None,
+            StreamError::Bits { error, .. } => Some(error),
+        }
+    }
+}
+
+/// Errors while reading bits, before the transmission number is attached.
+pub(crate) enum ReadError {
+    Io(io::Error),
+    InvalidHex { bit_pos: usize, byte: u8 },
+    TrailingData { bit_pos: usize },
+    Bits(BitsError),
+}
+
+impl From<BitsError> for ReadError {
+    fn from(err: BitsError) -> Self {
+        ReadError::Bits(err)
+    }
+}
+
+/// Decodes transmissions from a reader one top-level packet at a time,
+/// only buffering as much input as the reader needs.
+///
+/// Decoding stops at the first error, since there is no way to find the
+/// start of the next transmission in raw input.
+pub struct StreamDecoder<R> {
+    reader: BufReader<R>,
+    format: Format,
+    max_depth: usize,
+    /// The hex digit or byte being read, and how many of its low bits are
+    /// still unread.
+    unit: u8,
+    unit_bits: usize,
+    bit_pos: usize,
+    transmissions: usize,
+    failed: bool,
+}
+
+impl<R: Read> StreamDecoder<R> {
+    pub fn new(reader: R, format: Format) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            format,
+            max_depth: DEFAULT_MAX_DEPTH,
+            unit: 0,
+            unit_bits: 0,
+            bit_pos: 0,
+            transmissions: 0,
+            failed: false,
+        }
+    }
+
+    /// Allow operators nested at most `max_depth` deep.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Number of transmissions started so far.
+    pub fn transmissions(&self) -> usize {
+        self.transmissions
+    }
+
+    fn peek(&mut self) -> io::Result<Option<u8>> {
+        Ok(self.reader.fill_buf()?.first().copied())
+    }
+
+    /// Load the next digit or byte, returning false at the end of the
+    /// current transmission.
+    fn next_unit(&mut self) -> Result<bool, ReadError> {
+        let byte = match self.peek().map_err(ReadError::Io)? {
+            Some(b'\n' | b'\r') if self.format == Format::Hex => return Ok(false),
+            Some(byte) => byte,
+            None => return Ok(false),
+        };
+
+        (self.unit, self.unit_bits) = match self.format {
+            Format::Raw => (byte, 8),
+            Format::Hex => match char::from(byte).to_digit(16) {
+                Some(digit) => (digit as u8, 4),
+                None => {
+                    return Err(ReadError::InvalidHex {
+                        bit_pos: self.bit_pos,
+                        byte,
+                    })
+                }
+            },
+        };
+        self.reader.consume(1);
+
+        Ok(true)
+    }
+
+    /// Skip any blank lines and report whether another transmission follows.
+    fn at_transmission(&mut self) -> io::Result<bool> {
+        if self.format == Format::Hex {
+            while self.peek()?.is_some_and(|byte| byte.is_ascii_whitespace()) {
+                self.reader.consume(1);
+            }
+        }
+
+        Ok(self.peek()?.is_some())
+    }
+
+    /// Skip the padding after a packet: the rest of the line for hex, which
+    /// must be zeros apart from whitespace, and the rest of the byte for raw
+    /// input.
+    fn skip_padding(&mut self) -> Result<(), ReadError> {
+        let unit_bits = std::mem::take(&mut self.unit_bits);
+        if self.format == Format::Raw {
+            return Ok(());
+        }
+
+        if self.unit & ((1 << unit_bits) - 1) != 0 {
+            return Err(ReadError::TrailingData {
+                bit_pos: self.bit_pos,
+            });
+        }
+        self.bit_pos += unit_bits;
+        loop {
+            match self.peek().map_err(ReadError::Io)? {
+                None | Some(b'\n') => return Ok(()),
+                Some(b'0') => self.bit_pos += 4,
+                Some(byte) if byte.is_ascii_whitespace() => (),
+                Some(byte) if byte.is_ascii_hexdigit() => {
+                    return Err(ReadError::TrailingData {
+                        bit_pos: self.bit_pos,
+                    })
+                }
+                Some(byte) => {
+                    return Err(ReadError::InvalidHex {
+                        bit_pos: self.bit_pos,
+                        byte,
+                    })
+                }
+            }
+            self.reader.consume(1);
+        }
+    }
+}
+
+impl<R: Read> BitRead for StreamDecoder<R> {
+    type Error = ReadError;
+
+    fn bit_pos(&self) -> usize {
+        self.bit_pos
+    }
+
+    fn read(&mut self, num_bits: usize) -> Result<u64, ReadError> {
+        let start = self.bit_pos;
+        let mut retval = 0;
+        let mut bits_to_get = num_bits;
+
+        while bits_to_get > 0 {
+            if self.unit_bits == 0 && !self.next_unit()? {
+                return Err(BitsError::UnexpectedEof { bit_pos: start }.into());
+            }
+
+            let bits_grabbed = self.unit_bits.min(bits_to_get);
+            let bits =
+                (self.unit as u64 >> (self.unit_bits - bits_grabbed)) & ((1 << bits_grabbed) - 1);
+            retval = (retval << bits_grabbed) | bits;
+            self.unit_bits -= bits_grabbed;
+            bits_to_get -= bits_grabbed;
+            self.bit_pos += bits_grabbed;
+        }
+
+        Ok(retval)
+    }
+}
+
+impl<R: Read> Iterator for StreamDecoder<R> {
+    type Item = Result<Packet, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        match self.at_transmission() {
+            Ok(true) => (),
+            Ok(false) => return None,
+            Err(err) => {
+                self.failed = true;
+                return Some(Err(StreamError::Io(err)));
+            }
+        }
+
+        self.transmissions += 1;
+        self.bit_pos = 0;
+        let max_depth = self.max_depth;
+        let result =
+            parse(self, 0, max_depth, None).and_then(|packet| self.skip_padding().map(|_| packet));
+
+        let transmission = self.transmissions;
+        Some(result.map_err(|err| {
+            self.failed = true;
+            match err {
+                ReadError::Io(err) => StreamError::Io(err),
+                ReadError::InvalidHex { bit_pos, byte } => StreamError::InvalidHex {
+                    transmission,
+                    bit_pos,
+                    byte,
+                },
+                ReadError::TrailingData { bit_pos } => StreamError::TrailingData {
+                    transmission,
+                    bit_pos,
+                },
+                ReadError::Bits(error) => StreamError::Bits {
+                    transmission,
+                    error,
+                },
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{random_packet, Rng};
+    use crate::LengthType;
+
+    fn random_packets(count: usize) -> Vec<Packet> {
+        let mut rng = Rng::new(17);
+        (0..count).map(|_| random_packet(&mut rng, 4)).collect()
+    }
+
+    #[test]
+    fn test_stream_hex() {
+        let packets = random_packets(50);
+        let log: String = packets
+            .iter()
+            .map(|packet| packet.to_hex(LengthType::Count).unwrap() + "\n")
+            .collect();
+
+        let decoded: Vec<Packet> = StreamDecoder::new(log.as_bytes(), Format::Hex)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(decoded, packets);
+
+        // Odd padding, blank lines and a missing final newline.
+        let log = "\n  D2FE28\r\n\n38006F45291200\nEE00D40C823060";
+        let decoded: Vec<String> = StreamDecoder::new(log.as_bytes(), Format::Hex)
+            .map(|packet| packet.unwrap().to_string())
+            .collect();
+        assert_eq!(decoded, ["2021", "(lt 10 20)", "(max 1 2 3)"]);
+    }
+
+    #[test]
+    fn test_stream_raw() {
+        let packets = random_packets(50);
+        let log: Vec<u8> = packets
+            .iter()
+            .flat_map(|packet| packet.encode(LengthType::Bits).unwrap())
+            .collect();
+
+        let mut decoder = StreamDecoder::new(log.as_slice(), Format::Raw);
+        for packet in &packets {
+            assert_eq!(&decoder.next().unwrap().unwrap(), packet);
+        }
+        assert!(decoder.next().is_none());
+        assert_eq!(decoder.transmissions(), 50);
+    }
+
+    #[test]
+    fn test_stream_errors() {
+        let mut decoder = StreamDecoder::new("D2FE28\nD2FE\nD2FE28".as_bytes(), Format::Hex);
+        assert!(decoder.next().unwrap().is_ok());
+        assert!(matches!(
+            decoder.next(),
+            Some(Err(StreamError::Bits {
+                transmission: 2,
+                error: BitsError::UnexpectedEof { bit_pos: 16 },
+            }))
+        ));
+        assert!(decoder.next().is_none());
+
+        let mut decoder = StreamDecoder::new("D2FX28".as_bytes(), Format::Hex);
+        assert!(matches!(
+            decoder.next(),
+            Some(Err(StreamError::InvalidHex {
+                transmission: 1,
+                bit_pos: 12,
+                byte: b'X',
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_stream_padding() {
+        let log = "D2FE2800 \n38006F45291200";
+        let decoded: Vec<String> = StreamDecoder::new(log.as_bytes(), Format::Hex)
+            .map(|packet| packet.unwrap().to_string())
+            .collect();
+        assert_eq!(decoded, ["2021", "(lt 10 20)"]);
+
+        let mut decoder = StreamDecoder::new("D2FE28ZZZZ\nD2FE28".as_bytes(), Format::Hex);
+        assert!(matches!(
+            decoder.next(),
+            Some(Err(StreamError::InvalidHex {
+                transmission: 1,
+                bit_pos: 24,
+                byte: b'Z',
+            }))
+        ));
+        assert!(decoder.next().is_none());
+
+        // A second transmission on the same line isn't padding.
+        let mut decoder = StreamDecoder::new("D2FE28D2FE28".as_bytes(), Format::Hex);
+        assert!(matches!(
+            decoder.next(),
+            Some(Err(StreamError::TrailingData {
+                transmission: 1,
+                bit_pos: 24,
+            }))
+        ));
+
+        let mut decoder = StreamDecoder::new("D2FE29".as_bytes(), Format::Hex);
+        assert!(matches!(
+            decoder.next(),
+            Some(Err(StreamError::TrailingData {
+                transmission: 1,
+                bit_pos: 21,
+            }))
+        ));
+    }
+}
@@ -0,0 +1,138 @@
+use common::{parse_number, single_line, ParseError, Solution};
+use std::ops::RangeInclusive;
+
+fn solve1(xrange: &RangeInclusive<i32>, yrange: &RangeInclusive<i32>) -> i32 {
+    let mut max_y = 0;
+    for xv in (1..*xrange.end()).rev() {
+        for yv in 0..200 {
+            let mut x_velocity = xv;
+            let mut y_velocity = yv;
+            let mut cur_x = 0;
+            let mut cur_y = 0;
+            let mut max_y_pos = 0;
+
+            loop {
+                if (x_velocity == 0 && cur_x < *xrange.start())
+                    || cur_y < *yrange.start()
+                    || cur_x > *xrange.end()
+                {
+                    break;
+                }
+                cur_x += x_velocity;
+                if x_velocity > 0 {
+                    x_velocity -= 1;
+                }
+                cur_y += y_velocity;
+                y_velocity -= 1;
+
+                max_y_pos = max_y_pos.max(cur_y);
+
+                if xrange.contains(&cur_x) && yrange.contains(&cur_y) {
+                    max_y = max_y.max(max_y_pos);
+                    break;
+                }
+            }
+        }
+    }
+
+    max_y
+}
+
+fn solve2(xrange: &RangeInclusive<i32>, yrange: &RangeInclusive<i32>) -> i32 {
+    let mut valid_velocity = 0;
+    for xv in 1..=*xrange.end() {
+        for yv in -200..200 {
+            let mut x_velocity = xv;
+            let mut y_velocity = yv;
+            let mut cur_x = 0;
+            let mut cur_y = 0;
+
+            loop {
+                if (x_velocity == 0 && cur_x < *xrange.start())
+                    || cur_y < *yrange.start()
+                    || cur_x > *xrange.end()
+                {
+                    break;
+                }
+                cur_x += x_velocity;
+                if x_velocity > 0 {
+                    x_velocity -= 1;
+                }
+                cur_y += y_velocity;
+                y_velocity -= 1;
+
+                if xrange.contains(&cur_x) && yrange.contains(&cur_y) {
+                    valid_velocity += 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    valid_velocity
+}
+
+fn parse_input(input: &str) -> Result<(RangeInclusive<i32>, RangeInclusive<i32>), ParseError> {
+    let (idx, line) = single_line(input)?;
+    let bad_line = || ParseError::at(idx, line, line, "expected `target area: x=A..B, y=C..D`");
+    let (left, right) = line
+        .split_once(": ")
+        .ok_or_else(bad_line)?
+        .1
+        .split_once(", ")
+        .ok_or_else(bad_line)?;
+    let left = left.strip_prefix("x=").ok_or_else(bad_line)?;
+    let right = right.strip_prefix("y=").ok_or_else(bad_line)?;
+    let (xleft, xright) = left.split_once("..").ok_or_else(bad_line)?;
+    let (yleft, yright) = right.split_once("..").ok_or_else(bad_line)?;
+    let xrange: RangeInclusive<i32> = RangeInclusive::new(
+        parse_number(idx, line, xleft)?,
+        parse_number(idx, line, xright)?,
+    );
+    let yrange: RangeInclusive<i32> = RangeInclusive::new(
+        parse_number(idx, line, yleft)?,
+        parse_number(idx, line, yright)?,
+    );
+
+    Ok((xrange, yrange))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input<'a> = (RangeInclusive<i32>, RangeInclusive<i32>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<(RangeInclusive<i32>, RangeInclusive<i32>), ParseError> {
+        parse_input(input)
+    }
+
+    fn solve1((xrange, yrange): &(RangeInclusive<i32>, RangeInclusive<i32>)) -> i32 {
+        solve1(xrange, yrange)
+    }
+
+    fn solve2((xrange, yrange): &(RangeInclusive<i32>, RangeInclusive<i32>)) -> i32 {
+        solve2(xrange, yrange)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ops::RangeInclusive;
+
+    const INPUT: (RangeInclusive<i32>, RangeInclusive<i32>) = (20..=30, -10..=-5);
+
+    #[test]
+    fn test_solve1() {
+        assert_eq!(solve1(&INPUT.0, &INPUT.1), 45);
+    }
+
+    #[test]
+    fn test_solve2() {
+        assert_eq!(solve2(&INPUT.0, &INPUT.1), 112);
+    }
+}
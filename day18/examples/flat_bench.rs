@@ -0,0 +1,61 @@
+//! Compare the tree and flat snailfish representations on the puzzle input:
+//!
+//! ```text
+//! cargo run --release -p day18 --example flat_bench [input] [repeat]
+//! ```
+
+use common::bench::{measure, report};
+use day18::{Day18, FlatSnailNumber, SnailNumber};
+use std::env;
+use std::ops::Add;
+
+fn sum<T: Clone + Add<Output = T>>(numbers: &[T]) -> T {
+    numbers.iter().cloned().reduce(|sum, n| sum + n).unwrap()
+}
+
+fn best_pair<T>(numbers: &[T], magnitude: impl Fn(&T, &T) -> u64) -> u64 {
+    let mut max = 0;
+    for (i, left) in numbers.iter().enumerate() {
+        for (j, right) in numbers.iter().enumerate() {
+            if i != j {
+                max = max.max(magnitude(left, right));
+            }
+        }
+    }
+
+    max
+}
+
+fn main() {
+    let mut args = env::args_os().skip(1);
+    let input = common::load_or_exit::<Day18>(args.next());
+    let repeat = args
+        .next()
+        .and_then(|arg| arg.to_str()?.parse().ok())
+        .unwrap_or(10);
+
+    let trees: Vec<SnailNumber> = common::parse_or_exit::<Day18>(&input);
+    let flats: Vec<FlatSnailNumber> = trees.iter().map(FlatSnailNumber::from).collect();
+
+    let tree_pair = |a: &SnailNumber, b: &SnailNumber| (a + b).magnitude();
+    let flat_pair = |a: &FlatSnailNumber, b: &FlatSnailNumber| (a + b).magnitude();
+    assert_eq!(sum(&trees).magnitude(), sum(&flats).magnitude());
+    assert_eq!(best_pair(&trees, tree_pair), best_pair(&flats, flat_pair));
+
+    report("tree sum", &measure(repeat, || sum(&trees).magnitude()));
+    report("flat sum", &measure(repeat, || sum(&flats).magnitude()));
+    report(
+        "tree pairs",
+        &measure(repeat, || best_pair(&trees, tree_pair)),
+    );
+    report(
+        "flat pairs",
+        &measure(repeat, || best_pair(&flats, flat_pair)),
+    );
+    report("search", &measure(repeat, || day18::best_pair(&trees)));
+    #[cfg(feature = "rayon")]
+    report(
+        "par search",
+        &measure(repeat, || day18::par_best_pair(&trees)),
+    );
+}
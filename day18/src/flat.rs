@@ -0,0 +1,227 @@
+use crate::{Element, SnailNumber, SnailParseError};
+use std::fmt;
+use std::ops::Add;
+use std::str::FromStr;
+
+/// A snailfish number stored as its regular numbers from left to right,
+/// each with the number of pairs enclosing it.
+///
+/// Reduction scans and splices one vector instead of walking a tree from
+/// the root for every action, and cloning is a single allocation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlatSnailNumber {
+    cells: Vec<(u64, u8)>,
+}
+
+impl FlatSnailNumber {
+    /// The index of the left value of the leftmost pair of two regular
+    /// numbers inside four or more pairs. Deeper cells are not always such a
+    /// pair in parsed numbers, so siblings are found by folding completed
+    /// pairs on a stack, as in `magnitude`. Everything in such a pair is
+    /// deeper than four, so the fold can start at the first deep cell.
+    fn explodable(&self) -> Option<usize> {
+        let start = self.cells.iter().position(|&(_, depth)| depth > 4)?;
+        // The first deep cell is a left child, so a regular number at the
+        // same depth right after it is its sibling. This is the only case
+        // once numbers are reduced.
+        if self.cells.get(start + 1).map(|&(_, depth)| depth) == Some(self.cells[start].1) {
+            return Some(start);
+        }
+        // Depth and, for regular numbers, index of each unfinished element.
+        let mut stack: Vec<(u8, Option<usize>)> = Vec::new();
+        for (i, &(_, depth)) in self.cells.iter().enumerate().skip(start) {
+            stack.push((depth, Some(i)));
+            while let [.., (left_depth, left), (right_depth, right)] = stack[..] {
+                if left_depth != right_depth {
+                    break;
+                }
+                if let (Some(left), Some(_), 5..) = (left, right, left_depth) {
+                    return Some(left);
+                }
+                stack.truncate(stack.len() - 2);
+                stack.push((left_depth - 1, None));
+            }
+        }
+
+        None
+    }
+
+    fn reduce(&mut self) {
+        loop {
+            if let Some(i) = self.explodable() {
+                self.explode(i);
+            } else if let Some(i) = self.cells.iter().position(|&(value, _)| value >= 10) {
+                let (value, depth) = self.cells[i];
+                self.cells[i] = (value / 2, depth + 1);
+                self.cells.insert(i + 1, (value - value / 2, depth + 1));
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Explode the pair whose left value is at `i` and right value at `i + 1`.
+    fn explode(&mut self, i: usize) {
+        let (left, depth) = self.cells[i];
+        let (right, _) = self.cells.remove(i + 1);
+        if i > 0 {
+            self.cells[i - 1].0 += left;
+        }
+        if let Some(next) = self.cells.get_mut(i + 1) {
+            next.0 += right;
+        }
+        self.cells[i] = (0, depth - 1);
+    }
+
+    pub fn magnitude(&self) -> u64 {
+        let mut stack: Vec<(u64, u8)> = Vec::with_capacity(self.cells.len());
+        for &cell in &self.cells {
+            stack.push(cell);
+            while let [.., (left, left_depth), (right, right_depth)] = stack[..] {
+                if left_depth != right_depth {
+                    break;
+                }
+                stack.truncate(stack.len() - 2);
+                stack.push((3 * left + 2 * right, left_depth - 1));
+            }
+        }
+
+        stack.first().map_or(0, |&(value, _)| value)
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, idx: &mut usize, depth: u8) -> fmt::Result {
+        let (value, cell_depth) = self.cells[*idx];
+        if cell_depth == depth {
+            *idx += 1;
+            return write!(f, "{}", value);
+        }
+
+        write!(f, "[")?;
+        self.write(f, idx, depth + 1)?;
+        write!(f, ",")?;
+        self.write(f, idx, depth + 1)?;
+        write!(f, "]")
+    }
+}
+
+fn flatten(element: &Element, depth: u8, cells: &mut Vec<(u64, u8)>) {
+    match element {
+        Element::Value(value) => cells.push((*value, depth)),
+        Element::SnailNumber(number) => {
+            flatten(&number.left, depth + 1, cells);
+            flatten(&number.right, depth + 1, cells);
+        }
+    }
+}
+
+impl From<&SnailNumber> for FlatSnailNumber {
+    fn from(number: &SnailNumber) -> Self {
+        let mut cells = Vec::new();
+        flatten(&number.left, 1, &mut cells);
+        flatten(&number.right, 1, &mut cells);
+        Self { cells }
+    }
+}
+
+impl FromStr for FlatSnailNumber {
+    type Err = SnailParseError;
+
+    fn from_str(input: &str) -> Result<Self, SnailParseError> {
+        Ok(Self::from(&input.parse::<SnailNumber>()?))
+    }
+}
+
+impl fmt::Display for FlatSnailNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, &mut 0, 0)
+    }
+}
+
+impl Add for &FlatSnailNumber {
+    type Output = FlatSnailNumber;
+
+    fn add(self, other: Self) -> FlatSnailNumber {
+        let mut cells = Vec::with_capacity(self.cells.len() + other.cells.len());
+        cells.extend(
+            self.cells
+                .iter()
+                .chain(&other.cells)
+                .map(|&(v, d)| (v, d + 1)),
+        );
+        let mut new = FlatSnailNumber { cells };
+        new.reduce();
+        new
+    }
+}
+
+impl Add for FlatSnailNumber {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        &self + &other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
+        "[[[5,[2,8]],4],[5,[[9,9],0]]]",
+        "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
+        "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
+        "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
+        "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
+        "[[[[5,4],[7,7]],8],[[8,3],8]]",
+        "[[9,3],[[9,9],[6,[4,9]]]]",
+        "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
+        "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+    ];
+
+    #[test]
+    fn test_small() {
+        let left: FlatSnailNumber = "[[[[4,3],4],4],[7,[[8,4],9]]]".parse().unwrap();
+        let result = left + "[1,1]".parse().unwrap();
+        assert_eq!(result.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+        assert_eq!(result.magnitude(), 1384);
+    }
+
+    #[test]
+    fn test_deep() {
+        // Nested deeper than any sum gets, with pairs below depth four.
+        let numbers = [
+            "[[[[1,[2,3]],4],5],6]",
+            "[[[[[1,1],1],1],1],1]",
+            "[[[[[[9,9],9],9],9],9],[[[[[8,8],7],6],5],4]]",
+            "[[1,[2,[3,[4,[5,[6,7]]]]]],1]",
+            "[1,1]",
+        ];
+        for left in numbers {
+            for right in numbers {
+                let tree = left.parse::<SnailNumber>().unwrap() + right.parse().unwrap();
+                let flat = left.parse::<FlatSnailNumber>().unwrap() + right.parse().unwrap();
+                assert_eq!(flat.to_string(), tree.to_string());
+                assert_eq!(flat.magnitude(), tree.magnitude());
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_tree() {
+        let trees: Vec<SnailNumber> = INPUT.iter().map(|l| l.parse().unwrap()).collect();
+        let flats: Vec<FlatSnailNumber> = trees.iter().map(FlatSnailNumber::from).collect();
+
+        let tree_sum = trees.iter().cloned().reduce(|sum, n| sum + n).unwrap();
+        let flat_sum = flats.iter().cloned().reduce(|sum, n| sum + n).unwrap();
+        assert_eq!(flat_sum.to_string(), tree_sum.to_string());
+        assert_eq!(flat_sum.magnitude(), 4140);
+
+        for (i, left) in trees.iter().enumerate() {
+            for (j, right) in trees.iter().enumerate() {
+                let flat = &flats[i] + &flats[j];
+                assert_eq!(flat.to_string(), (left + right).to_string());
+            }
+        }
+    }
+}
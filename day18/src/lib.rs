@@ -0,0 +1,741 @@
+use common::{content_lines, ParseError, Solution};
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+
+mod flat;
+mod search;
+#[cfg(feature = "serde")]
+mod serialize;
+
+pub use flat::FlatSnailNumber;
+#[cfg(feature = "rayon")]
+pub use search::par_best_pair;
+pub use search::{best_pair, BestPair};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Element {
+    Value(u64),
+    SnailNumber(Box<SnailNumber>),
+}
+
+impl Element {
+    fn explode_right(&mut self, val: u64) {
+        match self {
+            Element::Value(cval) => {
+                *cval += val;
+            }
+            Element::SnailNumber(num) => {
+                num.right.explode_right(val);
+            }
+        }
+    }
+
+    fn explode_left(&mut self, val: u64) {
+        match self {
+            Element::Value(cval) => *cval += val,
+            Element::SnailNumber(num) => {
+                num.left.explode_left(val);
+            }
+        }
+    }
+}
+
+/// A snailfish number: a pair whose sides are regular numbers or pairs.
+///
+/// Numbers are ordered by magnitude, with equal magnitudes ordered by
+/// structure so that the ordering agrees with `==`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SnailNumber {
+    left: Element,
+    right: Element,
+}
+
+/// Which child of a pair to descend into.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// One step of reduction, locating the pair or regular number it acted on
+/// by the path from the root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Action {
+    Explode(Vec<Side>),
+    Split(Vec<Side>),
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, path) = match self {
+            Action::Explode(path) => ("explode", path),
+            Action::Split(path) => ("split", path),
+        };
+        write!(f, "{} at ", name)?;
+        for side in path {
+            match side {
+                Side::Left => write!(f, "L")?,
+                Side::Right => write!(f, "R")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An action taken while reducing and the number right after it.
+#[derive(Clone, Debug)]
+pub struct TraceStep {
+    pub action: Action,
+    pub state: SnailNumber,
+}
+
+#[derive(Debug)]
+enum ReduceResult {
+    SplitLeft(u64),
+    SplitRight(u64),
+    Explode(Option<u64>, Option<u64>),
+    Some, // operation concluded
+    None,
+}
+
+impl SnailNumber {
+    fn to_string_rec(&self, buffer: &mut String) {
+        buffer.push('[');
+        match &self.left {
+            Element::Value(val) => buffer.push_str(val.to_string().as_str()),
+            Element::SnailNumber(num) => num.to_string_rec(buffer),
+        }
+        buffer.push(',');
+        match &self.right {
+            Element::Value(val) => buffer.push_str(val.to_string().as_str()),
+            Element::SnailNumber(num) => num.to_string_rec(buffer),
+        }
+        buffer.push(']');
+    }
+
+    fn as_string(&self) -> String {
+        let mut output = String::new();
+        self.to_string_rec(&mut output);
+        output
+    }
+
+    /// Find and apply the leftmost explode (or split, without
+    /// `explode_rule`) below the pair at `path`, recording it in `action`.
+    ///
+    /// Parsed numbers can nest deeper than any sum would, so a pair inside
+    /// four pairs explodes only once both its sides are regular numbers;
+    /// otherwise the search carries on into its children.
+    fn reduce_rec(
+        &mut self,
+        path: &mut Vec<Side>,
+        explode_rule: bool,
+        action: &mut Option<Action>,
+    ) -> ReduceResult {
+        if explode_rule && path.len() >= 4 {
+            // explode case
+            if let (Element::Value(left), Element::Value(right)) = (&self.left, &self.right) {
+                *action = Some(Action::Explode(path.clone()));
+                return ReduceResult::Explode(Some(*left), Some(*right));
+            }
+        }
+
+        if !explode_rule {
+            // split case
+            if let Element::Value(left) = self.left {
+                if left >= 10 {
+                    path.push(Side::Left);
+                    *action = Some(Action::Split(path.clone()));
+                    path.pop();
+                    return ReduceResult::SplitLeft(left);
+                }
+            }
+        }
+
+        // left recurse
+        if let Element::SnailNumber(left) = &mut self.left {
+            path.push(Side::Left);
+            let result = left.reduce_rec(path, explode_rule, action);
+            path.pop();
+            match result {
+                ReduceResult::SplitLeft(val) => {
+                    let rem = val % 2;
+                    left.left = Element::SnailNumber(Box::new(SnailNumber {
+                        left: Element::Value(val / 2),
+                        right: Element::Value(val / 2 + rem),
+                    }));
+                    return ReduceResult::Some;
+                }
+                ReduceResult::SplitRight(val) => {
+                    let rem = val % 2;
+                    left.right = Element::SnailNumber(Box::new(SnailNumber {
+                        left: Element::Value(val / 2),
+                        right: Element::Value(val / 2 + rem),
+                    }));
+                    return ReduceResult::Some;
+                }
+                ReduceResult::Explode(Some(left), Some(right)) => {
+                    self.left = Element::Value(0);
+                    self.right.explode_left(right);
+                    return ReduceResult::Explode(Some(left), None);
+                }
+                ReduceResult::Explode(None, Some(right)) => {
+                    self.right.explode_left(right);
+                    return ReduceResult::Some;
+                }
+                ReduceResult::None => (),
+                _ => return result,
+            }
+        }
+
+        if !explode_rule {
+            if let Element::Value(right) = self.right {
+                if right >= 10 {
+                    path.push(Side::Right);
+                    *action = Some(Action::Split(path.clone()));
+                    path.pop();
+                    return ReduceResult::SplitRight(right);
+                }
+            }
+        }
+
+        // right recurse
+        if let Element::SnailNumber(right) = &mut self.right {
+            path.push(Side::Right);
+            let result = right.reduce_rec(path, explode_rule, action);
+            path.pop();
+            match result {
+                ReduceResult::SplitLeft(val) => {
+                    let rem = val % 2;
+                    right.left = Element::SnailNumber(Box::new(SnailNumber {
+                        left: Element::Value(val / 2),
+                        right: Element::Value(val / 2 + rem),
+                    }));
+                    return ReduceResult::Some;
+                }
+                ReduceResult::SplitRight(val) => {
+                    let rem = val % 2;
+                    right.right = Element::SnailNumber(Box::new(SnailNumber {
+                        left: Element::Value(val / 2),
+                        right: Element::Value(val / 2 + rem),
+                    }));
+                    return ReduceResult::Some;
+                }
+                ReduceResult::Explode(Some(left), Some(right)) => {
+                    self.right = Element::Value(0);
+                    self.left.explode_right(left);
+                    return ReduceResult::Explode(None, Some(right));
+                }
+                ReduceResult::Explode(Some(left), None) => {
+                    self.left.explode_right(left);
+                    return ReduceResult::Some;
+                }
+                ReduceResult::None => (),
+                _ => return result,
+            }
+        }
+
+        ReduceResult::None
+    }
+
+    /// Apply the next reduction action, if any: the leftmost explode, or
+    /// failing that the leftmost split.
+    fn reduce_step(&mut self) -> Option<Action> {
+        let mut path = Vec::new();
+        let mut action = None;
+        if let ReduceResult::None = self.reduce_rec(&mut path, true, &mut action) {
+            self.reduce_rec(&mut path, false, &mut action);
+        }
+
+        action
+    }
+
+    /// Explode and split until neither applies.
+    pub fn reduce(&mut self) {
+        while self.reduce_step().is_some() {}
+    }
+
+    /// Fully reduce, returning every action taken with the state after it.
+    pub fn reduce_with_trace(&mut self) -> Vec<TraceStep> {
+        std::iter::from_fn(|| {
+            let action = self.reduce_step()?;
+            Some(TraceStep {
+                action,
+                state: self.clone(),
+            })
+        })
+        .collect()
+    }
+
+    /// The pair `[left,right]`, without reducing it.
+    pub fn pair(left: SnailNumber, right: SnailNumber) -> SnailNumber {
+        SnailNumber {
+            left: Element::SnailNumber(Box::new(left)),
+            right: Element::SnailNumber(Box::new(right)),
+        }
+    }
+
+    pub fn magnitude(&self) -> u64 {
+        let mut tot = 0;
+        match &self.left {
+            Element::Value(val) => tot += val * 3,
+            Element::SnailNumber(node) => tot += node.magnitude() * 3,
+        }
+        match &self.right {
+            Element::Value(val) => tot += val * 2,
+            Element::SnailNumber(node) => tot += node.magnitude() * 2,
+        }
+
+        tot
+    }
+}
+
+/// Why a snailfish number could not be parsed, with the byte offset of the
+/// problem.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnailParseError {
+    pub offset: usize,
+    pub message: &'static str,
+}
+
+impl fmt::Display for SnailParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}", self.message, self.offset)
+    }
+}
+
+impl Error for SnailParseError {}
+
+/// Largest regular number the parser accepts. Bigger values take a split
+/// for every ten or so they exceed 9 to reduce, and with [`MAX_NESTING`]
+/// this keeps magnitudes of parsed numbers and their sums within a `u64`.
+pub const MAX_VALUE: u64 = 9999;
+
+/// How deeply pairs may nest in a parsed number, to keep parsing and the
+/// recursive operations on the result from overflowing the stack, and
+/// magnitudes from overflowing a `u64`.
+const MAX_NESTING: usize = 20;
+
+/// Recursive-descent parser for `[left,right]` pairs, where each side is a
+/// pair or a regular number, with whitespace allowed between tokens.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    /// Number of pairs currently open.
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &'static str) -> SnailParseError {
+        SnailParseError {
+            offset: self.pos,
+            message,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.input[self.pos..].chars().next()
+    }
+
+    fn expect(&mut self, expected: char, message: &'static str) -> Result<(), SnailParseError> {
+        if self.peek() != Some(expected) {
+            return Err(self.error(message));
+        }
+        self.pos += expected.len_utf8();
+        Ok(())
+    }
+
+    fn pair(&mut self) -> Result<SnailNumber, SnailParseError> {
+        if self.depth == MAX_NESTING {
+            return Err(self.error("pairs nested too deeply"));
+        }
+        self.expect('[', "expected `[`")?;
+        self.depth += 1;
+        let left = self.element()?;
+        self.expect(',', "expected `,`")?;
+        let right = self.element()?;
+        self.expect(']', "expected `]`")?;
+        self.depth -= 1;
+        Ok(SnailNumber { left, right })
+    }
+
+    fn element(&mut self) -> Result<Element, SnailParseError> {
+        match self.peek() {
+            Some('[') => Ok(Element::SnailNumber(Box::new(self.pair()?))),
+            Some(chr) if chr.is_ascii_digit() => {
+                let rest = &self.input[self.pos..];
+                let len = rest
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                let value = rest[..len]
+                    .parse()
+                    .ok()
+                    .filter(|&value| value <= MAX_VALUE)
+                    .ok_or_else(|| self.error("number too large"))?;
+                self.pos += len;
+                Ok(Element::Value(value))
+            }
+            _ => Err(self.error("expected `[` or a number")),
+        }
+    }
+}
+
+impl FromStr for SnailNumber {
+    type Err = SnailParseError;
+
+    fn from_str(input: &str) -> Result<Self, SnailParseError> {
+        let mut parser = Parser {
+            input,
+            pos: 0,
+            depth: 0,
+        };
+        let number = parser.pair()?;
+        if parser.peek().is_some() {
+            return Err(parser.error("expected end of input"));
+        }
+
+        Ok(number)
+    }
+}
+
+impl fmt::Display for SnailNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+impl fmt::Debug for SnailNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+/// Regular numbers before pairs, then by value or left to right.
+fn cmp_structure(a: &Element, b: &Element) -> Ordering {
+    match (a, b) {
+        (Element::Value(a), Element::Value(b)) => a.cmp(b),
+        (Element::Value(_), Element::SnailNumber(_)) => Ordering::Less,
+        (Element::SnailNumber(_), Element::Value(_)) => Ordering::Greater,
+        (Element::SnailNumber(a), Element::SnailNumber(b)) => {
+            cmp_structure(&a.left, &b.left).then_with(|| cmp_structure(&a.right, &b.right))
+        }
+    }
+}
+
+impl Ord for SnailNumber {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.magnitude()
+            .cmp(&other.magnitude())
+            .then_with(|| cmp_structure(&self.left, &other.left))
+            .then_with(|| cmp_structure(&self.right, &other.right))
+    }
+}
+
+impl PartialOrd for SnailNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Adds the numbers in order.
+///
+/// # Panics
+///
+/// Panics on an empty iterator, since snailfish numbers have no zero; use
+/// [`Iterator::reduce`] if there may be none.
+impl Sum for SnailNumber {
+    fn sum<I: Iterator<Item = SnailNumber>>(iter: I) -> Self {
+        iter.reduce(|sum, number| sum + number)
+            .expect("cannot sum no snailfish numbers")
+    }
+}
+
+impl<'a> Sum<&'a SnailNumber> for SnailNumber {
+    fn sum<I: Iterator<Item = &'a SnailNumber>>(iter: I) -> Self {
+        iter.cloned().sum()
+    }
+}
+
+/// Collecting adds the numbers in order, like [`Sum`].
+impl FromIterator<SnailNumber> for SnailNumber {
+    fn from_iter<I: IntoIterator<Item = SnailNumber>>(iter: I) -> Self {
+        iter.into_iter().sum()
+    }
+}
+
+impl Add for SnailNumber {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let mut new = SnailNumber::pair(self, other);
+        new.reduce();
+        new
+    }
+}
+
+impl Add for &SnailNumber {
+    type Output = SnailNumber;
+
+    fn add(self, other: Self) -> SnailNumber {
+        let mut new = SnailNumber::pair(self.clone(), other.clone());
+        new.reduce();
+        new
+    }
+}
+
+fn solve1(input: Vec<SnailNumber>) -> u64 {
+    input.into_iter().sum::<SnailNumber>().magnitude()
+}
+
+#[cfg(not(feature = "rayon"))]
+fn solve2(input: &[SnailNumber]) -> u64 {
+    best_pair(input).map_or(0, |pair| pair.magnitude)
+}
+
+#[cfg(feature = "rayon")]
+fn solve2(input: &[SnailNumber]) -> u64 {
+    par_best_pair(input).map_or(0, |pair| pair.magnitude)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input<'a> = Vec<SnailNumber>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<SnailNumber>, ParseError> {
+        if input.trim().is_empty() {
+            return Err(ParseError::at_end(0, "", "expected snailfish numbers"));
+        }
+        content_lines(input)
+            .map(|(idx, l)| {
+                l.parse().map_err(|err: SnailParseError| {
+                    // The whole number if it's a regular number at fault.
+                    let rest = &l[err.offset..];
+                    let len = match rest.find(|c: char| !c.is_ascii_digit()) {
+                        Some(0) => rest.chars().next().map_or(0, char::len_utf8),
+                        Some(len) => len,
+                        None => rest.len(),
+                    };
+                    ParseError::at(idx, l, &rest[..len], err.message)
+                })
+            })
+            .collect()
+    }
+
+    fn solve1(input: &Vec<SnailNumber>) -> u64 {
+        solve1(input.clone())
+    }
+
+    fn solve2(input: &Vec<SnailNumber>) -> u64 {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small() {
+        let input = SnailNumber::from_str("[[[[4,3],4],4],[7,[[8,4],9]]]").unwrap();
+        let result = input + SnailNumber::from_str("[1,1]").unwrap();
+        assert_eq!(
+            result.to_string().as_str(),
+            "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"
+        )
+    }
+
+    #[test]
+    fn test_parse() {
+        let number: SnailNumber = "[10,[3,4]]".parse().unwrap();
+        assert_eq!(number.to_string(), "[10,[3,4]]");
+
+        let number: SnailNumber = " [ [1, 2] ,\t[[123,4],5] ]\n".parse().unwrap();
+        assert_eq!(number.to_string(), "[[1,2],[[123,4],5]]");
+
+        let err = "[1,2".parse::<SnailNumber>().unwrap_err();
+        assert_eq!(
+            err,
+            SnailParseError {
+                offset: 4,
+                message: "expected `]`"
+            }
+        );
+        let err = "[1 2]".parse::<SnailNumber>().unwrap_err();
+        assert_eq!(err.offset, 3);
+        let err = "[[1,x],2]".parse::<SnailNumber>().unwrap_err();
+        assert_eq!(
+            err,
+            SnailParseError {
+                offset: 4,
+                message: "expected `[` or a number"
+            }
+        );
+        let err = "[1,2]]".parse::<SnailNumber>().unwrap_err();
+        assert_eq!(err.message, "expected end of input");
+
+        let deep = "[".repeat(MAX_NESTING) + "9999,9999]" + &",9999]".repeat(MAX_NESTING - 1);
+        let deep: SnailNumber = deep.parse().unwrap();
+        let magnitude = (1..MAX_NESTING).fold(5 * 9999, |inner, _| 3 * inner + 2 * 9999);
+        assert_eq!(deep.magnitude(), magnitude);
+        let err = ("[".repeat(100_000) + "1,1]")
+            .parse::<SnailNumber>()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            SnailParseError {
+                offset: MAX_NESTING,
+                message: "pairs nested too deeply"
+            }
+        );
+        let err = "[18446744073709551615,1]"
+            .parse::<SnailNumber>()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            SnailParseError {
+                offset: 1,
+                message: "number too large"
+            }
+        );
+        assert!("7".parse::<SnailNumber>().is_err());
+
+        assert!(Day18::parse(" \n").is_err());
+        let err = Day18::parse("[1,2]\n[3,a]").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "a"));
+        let err = Day18::parse("[1,2]\n[3,10000]").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "10000"));
+    }
+
+    #[test]
+    fn test_reduce_with_trace() {
+        let left = "[[[[4,3],4],4],[7,[[8,4],9]]]".parse().unwrap();
+        let mut sum = SnailNumber::pair(left, "[1,1]".parse().unwrap());
+        let trace: Vec<String> = sum
+            .reduce_with_trace()
+            .iter()
+            .map(|step| format!("{}: {}", step.action, step.state))
+            .collect();
+
+        assert_eq!(
+            trace,
+            [
+                "explode at LLLL: [[[[0,7],4],[7,[[8,4],9]]],[1,1]]",
+                "explode at LRRL: [[[[0,7],4],[15,[0,13]]],[1,1]]",
+                "split at LRL: [[[[0,7],4],[[7,8],[0,13]]],[1,1]]",
+                "split at LRRR: [[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]",
+                "explode at LRRR: [[[[0,7],4],[[7,8],[6,0]]],[8,1]]",
+            ]
+        );
+        assert!(sum.reduce_with_trace().is_empty());
+    }
+
+    #[test]
+    fn test_reduce_deep() {
+        // A pair below depth four that is not yet explodable.
+        let left: SnailNumber = "[[[[[1,1],1],1],1],1]".parse().unwrap();
+        let mut sum = SnailNumber::pair(left, "[1,1]".parse().unwrap());
+        let trace = sum.reduce_with_trace();
+        let actions: Vec<String> = trace.iter().map(|step| step.action.to_string()).collect();
+        assert_eq!(actions, ["explode at LLLLL", "explode at LLLL"]);
+        assert_eq!(sum.to_string(), "[[[[0,3],1],1],[1,1]]");
+        assert_eq!(sum.magnitude(), 196);
+
+        let numbers: Vec<SnailNumber> = [
+            "[[[[1,[2,3]],4],5],6]",
+            "[1,1]",
+            "[[[[[[9,9],9],9],9],9],9]",
+        ]
+        .iter()
+        .map(|l| l.parse().unwrap())
+        .collect();
+        let sum: SnailNumber = numbers.iter().sum();
+        assert!(!sum.to_string().contains("[[[[["));
+    }
+
+    #[test]
+    fn test_library_traits() {
+        let numbers: Vec<SnailNumber> = ["[[[[4,3],4],4],[7,[[8,4],9]]]", "[1,1]"]
+            .iter()
+            .map(|l| l.parse().unwrap())
+            .collect();
+        let expected: SnailNumber = "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]".parse().unwrap();
+        assert_eq!(numbers.iter().sum::<SnailNumber>(), expected);
+        assert_eq!(
+            numbers.clone().into_iter().collect::<SnailNumber>(),
+            expected
+        );
+        assert_eq!(
+            format!("{:?}", expected),
+            "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"
+        );
+
+        // Equal magnitudes fall back to comparing structure.
+        let a: SnailNumber = "[7,4]".parse().unwrap();
+        let b: SnailNumber = "[9,1]".parse().unwrap();
+        let c: SnailNumber = "[1,[9,1]]".parse().unwrap();
+        assert_eq!((a.magnitude(), b.magnitude(), c.magnitude()), (29, 29, 61));
+        assert!(a < b && b < c);
+        assert_eq!(a.cmp(&a.clone()), Ordering::Equal);
+        assert_eq!([&c, &a, &b].into_iter().max(), Some(&c));
+    }
+
+    #[test]
+    fn test_last_example() {
+        let input = &[
+            "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
+            "[[[5,[2,8]],4],[5,[[9,9],0]]]",
+            "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
+            "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
+            "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
+            "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
+            "[[[[5,4],[7,7]],8],[[8,3],8]]",
+            "[[9,3],[[9,9],[6,[4,9]]]]",
+            "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
+            "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+        ];
+
+        let parsed: Vec<SnailNumber> = input
+            .iter()
+            .map(|l| SnailNumber::from_str(l).unwrap())
+            .collect();
+
+        assert_eq!(solve1(parsed), 4140);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input = &[
+            "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
+            "[[[5,[2,8]],4],[5,[[9,9],0]]]",
+            "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
+            "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
+            "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
+            "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
+            "[[[[5,4],[7,7]],8],[[8,3],8]]",
+            "[[9,3],[[9,9],[6,[4,9]]]]",
+            "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
+            "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+        ];
+
+        let parsed: Vec<SnailNumber> = input
+            .iter()
+            .map(|l| SnailNumber::from_str(l).unwrap())
+            .collect();
+
+        assert_eq!(solve2(&parsed), 3993);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{FlatSnailNumber, SnailNumber};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// The two numbers, by index, whose sum has the largest magnitude.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BestPair {
+    pub left: usize,
+    pub right: usize,
+    pub magnitude: u64,
+}
+
+impl BestPair {
+    /// Larger magnitudes win, then the pair that comes first in the input,
+    /// so every search order finds the same pair.
+    fn better(self, other: Self) -> Self {
+        let key = |pair: &Self| {
+            (
+                pair.magnitude,
+                usize::MAX - pair.left,
+                usize::MAX - pair.right,
+            )
+        };
+        if key(&other) > key(&self) {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+fn add(flats: &[FlatSnailNumber], left: usize, right: usize) -> BestPair {
+    BestPair {
+        left,
+        right,
+        magnitude: (&flats[left] + &flats[right]).magnitude(),
+    }
+}
+
+/// Try every ordered pair of distinct numbers. Returns `None` for fewer
+/// than two numbers.
+pub fn best_pair(numbers: &[SnailNumber]) -> Option<BestPair> {
+    let flats: Vec<FlatSnailNumber> = numbers.iter().map(FlatSnailNumber::from).collect();
+    (0..flats.len())
+        .flat_map(|i| {
+            (0..flats.len())
+                .filter(move |&j| j != i)
+                .map(move |j| (i, j))
+        })
+        .map(|(i, j)| add(&flats, i, j))
+        .reduce(BestPair::better)
+}
+
+/// Like [`best_pair`], with one task per left-hand number.
+#[cfg(feature = "rayon")]
+pub fn par_best_pair(numbers: &[SnailNumber]) -> Option<BestPair> {
+    let flats: Vec<FlatSnailNumber> = numbers.par_iter().map(FlatSnailNumber::from).collect();
+    (0..flats.len())
+        .into_par_iter()
+        .flat_map_iter(|i| {
+            (0..flats.len())
+                .filter(move |&j| j != i)
+                .map(move |j| (i, j))
+        })
+        .map(|(i, j)| add(&flats, i, j))
+        .reduce_with(BestPair::better)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(lines: &[&str]) -> Vec<SnailNumber> {
+        lines.iter().map(|l| l.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_best_pair() {
+        let numbers = parse(&[
+            "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
+            "[[[5,[2,8]],4],[5,[[9,9],0]]]",
+            "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
+            "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
+            "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
+            "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
+            "[[[[5,4],[7,7]],8],[[8,3],8]]",
+            "[[9,3],[[9,9],[6,[4,9]]]]",
+            "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
+            "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+        ]);
+        let expected = BestPair {
+            left: 8,
+            right: 0,
+            magnitude: 3993,
+        };
+        assert_eq!(best_pair(&numbers), Some(expected));
+        #[cfg(feature = "rayon")]
+        assert_eq!(par_best_pair(&numbers), Some(expected));
+
+        assert_eq!(best_pair(&numbers[..1]), None);
+    }
+
+    #[test]
+    fn test_ties() {
+        // Every pair of equal numbers ties, so the first pair wins.
+        let numbers = parse(&["[1,1]", "[1,1]", "[1,1]", "[1,1]"]);
+        let expected = BestPair {
+            left: 0,
+            right: 1,
+            magnitude: 25,
+        };
+        assert_eq!(best_pair(&numbers), Some(expected));
+        #[cfg(feature = "rayon")]
+        for _ in 0..10 {
+            assert_eq!(par_best_pair(&numbers), Some(expected));
+        }
+    }
+}
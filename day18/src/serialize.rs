@@ -0,0 +1,112 @@
+use crate::{Element, SnailNumber, MAX_VALUE};
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+use std::fmt;
+
+/// Serialized as nested two-element arrays, so `[[1,2],3]` in JSON is the
+/// same as in snailfish notation.
+impl Serialize for SnailNumber {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut pair = serializer.serialize_tuple(2)?;
+        pair.serialize_element(&self.left)?;
+        pair.serialize_element(&self.right)?;
+        pair.end()
+    }
+}
+
+impl Serialize for Element {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Element::Value(value) => serializer.serialize_u64(*value),
+            Element::SnailNumber(number) => number.serialize(serializer),
+        }
+    }
+}
+
+struct PairVisitor;
+
+impl<'de> Visitor<'de> for PairVisitor {
+    type Value = SnailNumber;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a pair of numbers or pairs")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<SnailNumber, A::Error> {
+        let left = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let right = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(3, &self));
+        }
+
+        Ok(SnailNumber { left, right })
+    }
+}
+
+impl<'de> Deserialize<'de> for SnailNumber {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(2, PairVisitor)
+    }
+}
+
+struct ElementVisitor;
+
+impl<'de> Visitor<'de> for ElementVisitor {
+    type Value = Element;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a regular number up to {} or a pair", MAX_VALUE)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Element, E> {
+        if value > MAX_VALUE {
+            return Err(E::invalid_value(de::Unexpected::Unsigned(value), &self));
+        }
+        Ok(Element::Value(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Element, E> {
+        u64::try_from(value)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+            .and_then(|value| self.visit_u64(value))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Element, A::Error> {
+        PairVisitor
+            .visit_seq(seq)
+            .map(|number| Element::SnailNumber(Box::new(number)))
+    }
+}
+
+impl<'de> Deserialize<'de> for Element {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ElementVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json() {
+        let text = "[[1,2],[[10,3],4]]";
+        let number: SnailNumber = text.parse().unwrap();
+        assert_eq!(serde_json::to_string(&number).unwrap(), text);
+        assert_eq!(serde_json::from_str::<SnailNumber>(text).unwrap(), number);
+        assert_eq!(
+            serde_json::from_str::<SnailNumber>(" [ [1, 2],\n 3 ] ").unwrap(),
+            "[[1,2],3]".parse().unwrap()
+        );
+
+        assert!(serde_json::from_str::<SnailNumber>("[1,2,3]").is_err());
+        assert!(serde_json::from_str::<SnailNumber>("[1]").is_err());
+        assert!(serde_json::from_str::<SnailNumber>("[-1,2]").is_err());
+        assert!(serde_json::from_str::<SnailNumber>("[10000,2]").is_err());
+        assert!(serde_json::from_str::<SnailNumber>("7").is_err());
+    }
+}
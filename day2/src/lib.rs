@@ -0,0 +1,127 @@
+use common::{parse_number, ParseError, Solution};
+
+#[derive(Debug)]
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+fn parse_input(input: &[&str]) -> Result<Vec<Command>, ParseError> {
+    let mut parsed = Vec::new();
+    let lines = input.iter().enumerate();
+    for (idx, line) in lines.skip_while(|(_, line)| line.trim().is_empty()) {
+        let mut split = line.split_whitespace();
+        let dir = split
+            .next()
+            .ok_or_else(|| ParseError::at_end(idx, line, "expected a command"))?;
+        let num = split
+            .next()
+            .ok_or_else(|| ParseError::at_end(idx, line, "expected a distance"))?;
+        let num: i32 = parse_number(idx, line, num)?;
+
+        let command = match dir {
+            "forward" => Command::Forward(num),
+            "down" => Command::Down(num),
+            "up" => Command::Up(num),
+            _ => return Err(ParseError::at(idx, line, dir, "unknown command")),
+        };
+        parsed.push(command);
+    }
+
+    Ok(parsed)
+}
+
+fn solve1(input: &[Command]) -> i32 {
+    let mut horizontal = 0;
+    let mut depth = 0;
+
+    for command in input {
+        match command {
+            Command::Forward(val) => horizontal += val,
+            Command::Down(val) => depth += val,
+            Command::Up(val) => depth -= val,
+        }
+    }
+
+    horizontal * depth
+}
+
+fn solve2(input: &[Command]) -> i32 {
+    let mut horizontal = 0;
+    let mut depth = 0;
+    let mut aim = 0;
+
+    for command in input {
+        match command {
+            Command::Forward(val) => {
+                horizontal += val;
+                depth += val * aim;
+            }
+            Command::Down(val) => aim += val,
+            Command::Up(val) => aim -= val,
+        }
+    }
+
+    horizontal * depth
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Command>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+        let input: Vec<&str> = input.trim_end().lines().collect();
+        parse_input(&input)
+    }
+
+    fn solve1(input: &Vec<Command>) -> i32 {
+        solve1(input)
+    }
+
+    fn solve2(input: &Vec<Command>) -> i32 {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "forward 5",
+        "down 5",
+        "forward 8",
+        "up 3",
+        "down 8",
+        "forward 2",
+    ];
+
+    #[test]
+    fn test_solve1() {
+        let parsed = parse_input(INPUT).unwrap();
+        assert_eq!(solve1(&parsed), 150);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let parsed = parse_input(INPUT).unwrap();
+        assert_eq!(solve2(&parsed), 900);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input(&["forward 5", "sideways 3"]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.text, "sideways");
+
+        let err = parse_input(&["down x1"]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 6));
+        assert_eq!(err.text, "x1");
+    }
+}
@@ -0,0 +1,40 @@
+//! Compare the sparse and dense image backends on the puzzle input:
+//!
+//! ```text
+//! cargo run --release -p day20 --example backends [input] [iterations]
+//! ```
+
+use common::bench::{measure, report};
+use day20::{CompressedImage, Day20, Image, SparseImage};
+use std::env;
+
+fn enhance<I: Image>(image: &CompressedImage<I>, iterations: usize) -> Option<usize> {
+    let mut image = image.clone();
+    image.decompress(iterations);
+    image.image().lit_count()
+}
+
+fn main() {
+    let mut args = env::args_os().skip(1);
+    let input = common::load_or_exit::<Day20>(args.next());
+    let iterations = args
+        .next()
+        .and_then(|arg| arg.to_str()?.parse().ok())
+        .unwrap_or(1000);
+
+    let dense = common::parse_or_exit::<Day20>(&input);
+    let sparse = CompressedImage::<SparseImage>::parse(&input).unwrap();
+
+    assert_eq!(enhance(&sparse, 50), enhance(&dense, 50));
+    report("sparse 50", &measure(3, || enhance(&sparse, 50)));
+    report("dense 50", &measure(3, || enhance(&dense, 50)));
+    report(
+        &format!("dense {}", iterations),
+        &measure(1, || enhance(&dense, iterations)),
+    );
+
+    match enhance(&dense, iterations) {
+        Some(count) => println!("{} pixels lit after {} steps", count, iterations),
+        None => println!("infinitely many pixels lit after {} steps", iterations),
+    }
+}
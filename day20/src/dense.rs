@@ -0,0 +1,205 @@
+use crate::{Image, Window};
+use std::mem;
+
+/// Pixels per word.
+const BITS: usize = 64;
+
+/// The bits of a 9-bit lookup index that hold one column of the 3x3
+/// neighbourhood.
+const COLUMN: usize = 0b001001001;
+
+/// An image storing every pixel of a canvas around the window as one bit,
+/// enhancing into a second buffer and swapping.
+///
+/// Every bit outside the window, including the padding at the end of each
+/// row, holds the background. Enhancing whole words keeps that true, since
+/// those pixels only see background, and the canvas only has to be
+/// reallocated when the window reaches its edge.
+#[derive(Clone, Debug)]
+pub struct DenseImage {
+    window: Window,
+    canvas: Window,
+    /// Words per canvas row.
+    stride: usize,
+    front: Vec<u64>,
+    back: Vec<u64>,
+    background: bool,
+}
+
+fn fill(background: bool) -> u64 {
+    if background {
+        u64::MAX
+    } else {
+        0
+    }
+}
+
+/// Bit `bit` of each of three rows' words, as a column of a lookup index.
+fn column(above: u64, middle: u64, below: u64, bit: usize) -> usize {
+    ((above >> bit & 1) << 6 | (middle >> bit & 1) << 3 | (below >> bit & 1)) as usize
+}
+
+/// A canvas around `window` with room for it to grow for a while.
+fn canvas(window: Window) -> Window {
+    window.grow((window.width().max(window.height()) / 2 + 8) as isize)
+}
+
+impl DenseImage {
+    fn with_canvas(window: Window, canvas: Window, background: bool) -> Self {
+        let stride = canvas.width().div_ceil(BITS);
+        let front = vec![fill(background); stride * canvas.height()];
+        Self {
+            window,
+            canvas,
+            stride,
+            back: front.clone(),
+            front,
+            background,
+        }
+    }
+
+    /// The canvas column and row of `(x, y)`, which must be on the canvas.
+    fn offset(&self, x: isize, y: isize) -> (usize, usize) {
+        (
+            (x - self.canvas.min_x) as usize,
+            (y - self.canvas.min_y) as usize,
+        )
+    }
+
+    fn bit(row: &[u64], cx: usize) -> bool {
+        row[cx / BITS] >> (cx % BITS) & 1 == 1
+    }
+
+    /// Move to a bigger canvas, keeping the pixels in the window.
+    fn grow_canvas(&mut self) {
+        let mut resized = Self::with_canvas(self.window, canvas(self.window), self.background);
+        for (x, y) in self.window.positions() {
+            let lit = self.get(x, y);
+            if lit != self.background {
+                resized.set(x, y, lit);
+            }
+        }
+
+        *self = resized;
+    }
+}
+
+impl Image for DenseImage {
+    fn new(window: Window, background: bool) -> Self {
+        Self::with_canvas(window, canvas(window), background)
+    }
+
+    fn window(&self) -> Window {
+        self.window
+    }
+
+    fn background(&self) -> bool {
+        self.background
+    }
+
+    fn get(&self, x: isize, y: isize) -> bool {
+        if !self.window.contains(x, y) {
+            return self.background;
+        }
+
+        let (cx, cy) = self.offset(x, y);
+        Self::bit(&self.front[cy * self.stride..], cx)
+    }
+
+    fn set(&mut self, x: isize, y: isize, lit: bool) {
+        assert!(
+            self.window.contains(x, y),
+            "({}, {}) is outside the window",
+            x,
+            y
+        );
+        let (cx, cy) = self.offset(x, y);
+        let word = &mut self.front[cy * self.stride + cx / BITS];
+        let mask = 1 << (cx % BITS);
+        if lit {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    fn lit_count(&self) -> Option<usize> {
+        (!self.background).then(|| {
+            self.front
+                .iter()
+                .map(|word| word.count_ones() as usize)
+                .sum()
+        })
+    }
+
+    /// Slides a 9-bit window along each row: moving one pixel right shifts
+    /// the index left and brings in the next column from the three rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `algorithm` has 512 entries.
+    fn enhance(&mut self, algorithm: &[bool]) {
+        let window = self.window.grow(1);
+        if !(self.canvas.contains(window.min_x, window.min_y)
+            && self.canvas.contains(window.max_x, window.max_y))
+        {
+            self.grow_canvas();
+        }
+
+        let algorithm: &[bool; 512] = algorithm
+            .try_into()
+            .expect("expected 512 algorithm entries");
+        let background = algorithm[if self.background { 0b111111111 } else { 0 }];
+        let stride = self.stride;
+        let rows = self.offset(0, window.min_y).1..=self.offset(0, window.max_y).1;
+        let words = self.offset(window.min_x, 0).0 / BITS..=self.offset(window.max_x, 0).0 / BITS;
+        // Rows and columns off the canvas are all background.
+        let blank = vec![fill(self.background); stride];
+        let edge = if self.background { COLUMN } else { 0 };
+
+        for (cy, out) in self.back.chunks_exact_mut(stride).enumerate() {
+            if !rows.contains(&cy) {
+                out.fill(fill(background));
+                continue;
+            }
+
+            let row = |cy: usize| self.front.get(cy * stride..(cy + 1) * stride);
+            let above = cy.checked_sub(1).and_then(row).unwrap_or(&blank);
+            let middle = row(cy).unwrap_or(&blank);
+            let below = row(cy + 1).unwrap_or(&blank);
+            let column_at = |w: usize, bit| match (above.get(w), middle.get(w), below.get(w)) {
+                (Some(&a), Some(&m), Some(&b)) => column(a, m, b, bit),
+                _ => edge,
+            };
+
+            let first = *words.start();
+            let previous = first
+                .checked_sub(1)
+                .map_or(edge, |w| column_at(w, BITS - 1));
+            let mut index = previous << 1 | column_at(first, 0);
+            for (w, out) in out.iter_mut().enumerate() {
+                if !words.contains(&w) {
+                    *out = fill(background);
+                    continue;
+                }
+
+                let (a, m, b) = (above[w], middle[w], below[w]);
+                let next = column_at(w + 1, 0);
+                let mut word = 0;
+                for bit in 0..BITS {
+                    let column = match bit + 1 {
+                        BITS => next,
+                        bit => column(a, m, b, bit),
+                    };
+                    index = (index << 1 & !COLUMN & 0b111111111) | column;
+                    word |= (algorithm[index] as u64) << bit;
+                }
+                *out = word;
+            }
+        }
+
+        mem::swap(&mut self.front, &mut self.back);
+        self.window = window;
+        self.background = background;
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{CompressedImage, Image, Window};
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Image file formats. Lit pixels are black in all of them, like `#` on
+/// paper.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Binary portable bitmap (P4).
+    Pbm,
+    /// 1-bit greyscale PNG.
+    #[cfg(feature = "png")]
+    Png,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Pbm => "pbm",
+            #[cfg(feature = "png")]
+            Format::Png => "png",
+        }
+    }
+
+    /// The format with the given extension, if it is supported.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "pbm" => Some(Format::Pbm),
+            #[cfg(feature = "png")]
+            "png" => Some(Format::Png),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ExportError {
+    Io(io::Error),
+    #[cfg(feature = "png")]
+    Png(png::EncodingError),
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportError::Io(err) => write!(f, "could not write image: {}", err),
+            #[cfg(feature = "png")]
+            ExportError::Png(err) => write!(f, "could not encode PNG: {}", err),
+        }
+    }
+}
+
+impl Error for ExportError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ExportError::Io(err) => Some(err),
+            #[cfg(feature = "png")]
+            ExportError::Png(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for ExportError {
+    fn from(err: io::Error) -> Self {
+        ExportError::Io(err)
+    }
+}
+
+#[cfg(feature = "png")]
+impl From<png::EncodingError> for ExportError {
+    fn from(err: png::EncodingError) -> Self {
+        ExportError::Png(err)
+    }
+}
+
+/// Row `y` of `window`, eight pixels per byte with the leftmost in the high
+/// bit, and `ink` for lit pixels.
+fn pack_row(image: &impl Image, window: Window, y: isize, ink: bool) -> Vec<u8> {
+    let mut row = vec![0; window.width().div_ceil(8)];
+    for (i, x) in (window.min_x..=window.max_x).enumerate() {
+        if image.get(x, y) == ink {
+            row[i / 8] |= 0x80 >> (i % 8);
+        }
+    }
+
+    row
+}
+
+/// Write `window` of `image` as a binary PBM. The window may extend past
+/// the image's own window into the background.
+pub fn write_pbm(image: &impl Image, window: Window, out: &mut impl Write) -> io::Result<()> {
+    write!(out, "P4\n{} {}\n", window.width(), window.height())?;
+    for y in window.min_y..=window.max_y {
+        out.write_all(&pack_row(image, window, y, true))?;
+    }
+
+    Ok(())
+}
+
+/// Write `window` of `image` as a 1-bit greyscale PNG.
+#[cfg(feature = "png")]
+pub fn write_png(
+    image: &impl Image,
+    window: Window,
+    out: &mut impl Write,
+) -> Result<(), png::EncodingError> {
+    let mut encoder = png::Encoder::new(out, window.width() as u32, window.height() as u32);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::One);
+
+    let data: Vec<u8> = (window.min_y..=window.max_y)
+        .flat_map(|y| pack_row(image, window, y, false))
+        .collect();
+    encoder.write_header()?.write_image_data(&data)
+}
+
+pub fn write(
+    image: &impl Image,
+    window: Window,
+    format: Format,
+    out: &mut impl Write,
+) -> Result<(), ExportError> {
+    match format {
+        Format::Pbm => write_pbm(image, window, out)?,
+        #[cfg(feature = "png")]
+        Format::Png => write_png(image, window, out)?,
+    }
+
+    Ok(())
+}
+
+/// Write `window` of `image` to a new file at `path`.
+pub fn save(
+    image: &impl Image,
+    window: Window,
+    format: Format,
+    path: &Path,
+) -> Result<(), ExportError> {
+    let mut out = BufWriter::new(File::create(path)?);
+    write(image, window, format, &mut out)?;
+    out.flush()?;
+
+    Ok(())
+}
+
+/// Save the image before enhancing and after each of `steps` steps as
+/// `step-000.pbm` and so on in `dir`, creating it if needed. Every frame
+/// covers the window after the last step, so the frames line up.
+pub fn save_frames<I: Image>(
+    image: &CompressedImage<I>,
+    steps: usize,
+    format: Format,
+    dir: &Path,
+) -> Result<Vec<PathBuf>, ExportError> {
+    fs::create_dir_all(dir)?;
+    let window = image.image.window().grow(steps as isize);
+    let digits = steps.to_string().len().max(3);
+
+    let mut image = image.clone();
+    let mut paths = Vec::with_capacity(steps + 1);
+    for step in 0..=steps {
+        if step > 0 {
+            image.decompress(1);
+        }
+        let path = dir.join(format!(
+            "step-{:0digits$}.{}",
+            step,
+            format.extension(),
+            digits = digits
+        ));
+        save(&image.image, window, format, &path)?;
+        paths.push(path);
+    }
+
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DenseImage;
+
+    fn example() -> DenseImage {
+        let mut image = DenseImage::new(Window::new(10, 2), false);
+        for (x, y) in [(0, 0), (9, 0), (1, 1)] {
+            image.set(x, y, true);
+        }
+        image
+    }
+
+    #[test]
+    fn test_pbm() {
+        let image = example();
+        let mut out = Vec::new();
+        write_pbm(&image, image.window(), &mut out).unwrap();
+        assert_eq!(out, b"P4\n10 2\n\x80\x40\x40\x00");
+
+        // Outside the image's window is background.
+        let mut out = Vec::new();
+        write_pbm(&image, image.window().grow(1), &mut out).unwrap();
+        assert_eq!(out, b"P4\n12 4\n\x00\x00\x40\x20\x20\x00\x00\x00");
+    }
+
+    #[test]
+    fn test_save_frames() {
+        let algorithm = (0..512).map(|index| index & 0b000010000 == 0).collect();
+        let image = CompressedImage {
+            enhancement_algorithm: algorithm,
+            image: example(),
+        };
+
+        let dir = std::env::temp_dir().join(format!("day20-frames-{}", std::process::id()));
+        let paths = save_frames(&image, 2, Format::Pbm, &dir).unwrap();
+        let names: Vec<_> = paths.iter().map(|path| path.file_name().unwrap()).collect();
+        assert_eq!(names, ["step-000.pbm", "step-001.pbm", "step-002.pbm"]);
+
+        // The inverting algorithm puts the image back after two steps.
+        let first = fs::read(&paths[0]).unwrap();
+        assert!(first.starts_with(b"P4\n14 6\n"));
+        assert_eq!(fs::read(&paths[2]).unwrap(), first);
+        assert_ne!(fs::read(&paths[1]).unwrap(), first);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn test_png() {
+        let image = example();
+        let mut out = Vec::new();
+        write_png(&image, image.window(), &mut out).unwrap();
+
+        let mut reader = png::Decoder::new(out.as_slice()).read_info().unwrap();
+        let mut data = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut data).unwrap();
+        assert_eq!((info.width, info.height), (10, 2));
+        assert_eq!(&data[..info.buffer_size()], [0x7f, 0x80, 0xbf, 0xc0]);
+    }
+}
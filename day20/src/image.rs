@@ -0,0 +1,169 @@
+/// An inclusive rectangle of the plane. Empty when `max_x < min_x` or
+/// `max_y < min_y`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Window {
+    pub min_x: isize,
+    pub min_y: isize,
+    pub max_x: isize,
+    pub max_y: isize,
+}
+
+impl Window {
+    /// The window with its top left corner at the origin.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            min_x: 0,
+            min_y: 0,
+            max_x: width as isize - 1,
+            max_y: height as isize - 1,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max_x - self.min_x + 1).max(0) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max_y - self.min_y + 1).max(0) as usize
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+
+    /// The window with `by` more pixels on every side.
+    pub fn grow(&self, by: isize) -> Self {
+        Self {
+            min_x: self.min_x - by,
+            min_y: self.min_y - by,
+            max_x: self.max_x + by,
+            max_y: self.max_y + by,
+        }
+    }
+
+    /// Every position in the window, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (isize, isize)> {
+        let Self {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        } = *self;
+        (min_y..=max_y).flat_map(move |y| (min_x..=max_x).map(move |x| (x, y)))
+    }
+}
+
+/// An infinite image: the pixels inside the window are stored and every
+/// pixel outside it has the background value.
+pub trait Image: Clone {
+    /// An image with every pixel set to `background`.
+    fn new(window: Window, background: bool) -> Self;
+
+    fn window(&self) -> Window;
+
+    fn background(&self) -> bool;
+
+    fn get(&self, x: isize, y: isize) -> bool;
+
+    /// # Panics
+    ///
+    /// Panics if `(x, y)` is outside the window.
+    fn set(&mut self, x: isize, y: isize, lit: bool);
+
+    /// Number of lit pixels, or `None` if the background is lit and there
+    /// are infinitely many.
+    fn lit_count(&self) -> Option<usize>;
+
+    /// Apply an enhancement algorithm. Pixels next to the window can change,
+    /// so the window grows by one; the background changes everywhere else.
+    fn enhance(&mut self, algorithm: &[bool]);
+
+    /// The algorithm index for the pixel at `(x, y)`: its 3x3 neighbourhood
+    /// read row by row as a binary number.
+    fn lookup(&self, x: isize, y: isize) -> usize {
+        let mut output = 0;
+        for ydiff in -1..=1 {
+            for xdiff in -1..=1 {
+                output = (output << 1) | self.get(x + xdiff, y + ydiff) as usize;
+            }
+        }
+        output
+    }
+
+    /// The window as rows of `#` and `.`.
+    fn render(&self) -> String {
+        let window = self.window();
+        let mut output = String::with_capacity((window.width() + 1) * window.height());
+        for y in window.min_y..=window.max_y {
+            for x in window.min_x..=window.max_x {
+                output.push(if self.get(x, y) { '#' } else { '.' });
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DenseImage, SparseImage};
+
+    /// Lights each pixel that was dark, so index 0 is `#` and the
+    /// background flips every step.
+    fn invert() -> Vec<bool> {
+        (0..512).map(|index| index & 0b000010000 == 0).collect()
+    }
+
+    #[test]
+    fn test_window() {
+        let window = Window::new(5, 2);
+        assert_eq!((window.width(), window.height()), (5, 2));
+        assert!(window.contains(4, 1) && !window.contains(1, 4));
+        assert_eq!(window.positions().nth(5), Some((0, 1)));
+
+        let grown = window.grow(1);
+        assert_eq!((grown.width(), grown.height()), (7, 4));
+        assert_eq!(grown.positions().next(), Some((-1, -1)));
+
+        let empty = Window::new(0, 0);
+        assert_eq!((empty.width(), empty.positions().count()), (0, 0));
+    }
+
+    fn check_background<I: Image>() {
+        // Wide enough that pixels right of the window have x > max_y.
+        let mut image = I::new(Window::new(5, 2), false);
+        let lit = [(0, 0), (4, 0), (1, 1), (3, 1), (4, 1)];
+        for (x, y) in lit {
+            image.set(x, y, true);
+        }
+
+        let mut inverted = image.clone();
+        inverted.enhance(&invert());
+        assert_eq!(inverted.lit_count(), None);
+        assert!(inverted.get(-100, 50) && inverted.get(5, 0) && inverted.get(2, -1));
+        assert!(!inverted.get(4, 0) && inverted.get(2, 0));
+
+        let mut restored = inverted;
+        restored.enhance(&invert());
+        assert_eq!(restored.lit_count(), Some(lit.len()));
+        assert_eq!(
+            restored.render(),
+            "\
+.........
+.........
+..#...#..
+...#.##..
+.........
+.........
+"
+        );
+    }
+
+    #[test]
+    fn test_background() {
+        check_background::<SparseImage>();
+        check_background::<DenseImage>();
+    }
+}
@@ -0,0 +1,283 @@
+use common::{content_lines, ParseError, Solution};
+use std::fmt;
+
+mod dense;
+pub mod export;
+mod image;
+mod sparse;
+
+pub use dense::DenseImage;
+pub use image::{Image, Window};
+pub use sparse::SparseImage;
+
+/// An enhancement algorithm and the image to enhance, stored in any
+/// [`Image`] backend.
+#[derive(Clone)]
+pub struct CompressedImage<I = DenseImage> {
+    enhancement_algorithm: Vec<bool>,
+    image: I,
+}
+
+impl<I: Image> CompressedImage<I> {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut input_iter = content_lines(input);
+        let (idx, line) = input_iter
+            .next()
+            .ok_or_else(|| ParseError::at_end(0, "", "expected an enhancement algorithm"))?;
+        if let Some(bad) = line.split(['#', '.']).find(|s| !s.is_empty()) {
+            return Err(ParseError::at(idx, line, bad, "expected `#` or `.`"));
+        }
+        if line.len() != 512 {
+            return Err(ParseError::at(
+                idx,
+                line,
+                line,
+                "expected 512 algorithm entries",
+            ));
+        }
+        let enhancement_algorithm: Vec<bool> = line.chars().map(|c| c == '#').collect();
+        match input_iter.next() {
+            Some((_, blank)) if blank.trim().is_empty() => (),
+            Some((idx, line)) => {
+                return Err(ParseError::at(
+                    idx,
+                    line,
+                    line,
+                    "expected a blank line after the algorithm",
+                ))
+            }
+            None => return Err(ParseError::at_end(idx, line, "expected an image")),
+        }
+
+        let mut rows = Vec::new();
+        let mut width = None;
+        for (idx, row) in input_iter {
+            let row = row.trim();
+            if let Some(bad) = row.split(['#', '.']).find(|s| !s.is_empty()) {
+                return Err(ParseError::at(idx, row, bad, "expected `#` or `.`"));
+            }
+            let width = *width.get_or_insert(row.len());
+            if row.len() > width {
+                return Err(ParseError::at(
+                    idx,
+                    row,
+                    &row[width..],
+                    format!("expected {} pixels", width),
+                ));
+            } else if row.len() < width {
+                return Err(ParseError::at_end(
+                    idx,
+                    row,
+                    format!("expected {} pixels", width),
+                ));
+            }
+            rows.push(row);
+        }
+
+        let width = width.unwrap_or(0);
+        let mut image = I::new(Window::new(width, rows.len()), false);
+        for (y, row) in rows.iter().enumerate() {
+            for (x, col) in row.chars().enumerate() {
+                image.set(x as isize, y as isize, col == '#');
+            }
+        }
+
+        Ok(CompressedImage {
+            enhancement_algorithm,
+            image,
+        })
+    }
+
+    pub fn image(&self) -> &I {
+        &self.image
+    }
+
+    /// Apply the enhancement algorithm `iterations` times.
+    pub fn decompress(&mut self, iterations: usize) {
+        for _ in 0..iterations {
+            self.image.enhance(&self.enhancement_algorithm);
+        }
+    }
+}
+
+/// How many pixels are lit, which is infinitely many when the background
+/// is lit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LitCount(pub Option<usize>);
+
+impl fmt::Display for LitCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(count) => write!(f, "{}", count),
+            None => write!(f, "infinite"),
+        }
+    }
+}
+
+fn solve1<I: Image>(mut image: CompressedImage<I>) -> LitCount {
+    image.decompress(2);
+    LitCount(image.image.lit_count())
+}
+
+fn solve2<I: Image>(mut image: CompressedImage<I>) -> LitCount {
+    image.decompress(50);
+    LitCount(image.image.lit_count())
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input<'a> = CompressedImage;
+    type Answer1 = LitCount;
+    type Answer2 = LitCount;
+
+    fn parse(input: &str) -> Result<CompressedImage, ParseError> {
+        CompressedImage::parse(input)
+    }
+
+    fn solve1(input: &CompressedImage) -> LitCount {
+        solve1(input.clone())
+    }
+
+    fn solve2(input: &CompressedImage) -> LitCount {
+        solve2(input.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+    #..#.
+    #....
+    ##..#
+    ..#..
+    ..###"#;
+
+    #[test]
+    fn test_lookup() {
+        let cimage = CompressedImage::<SparseImage>::parse(INPUT).unwrap();
+        assert_eq!(cimage.image.lookup(2, 2), 34);
+    }
+
+    #[test]
+    fn test_solve1() {
+        let cimage = CompressedImage::<SparseImage>::parse(INPUT).unwrap();
+        assert_eq!(solve1(cimage), LitCount(Some(35)));
+        let cimage = CompressedImage::<DenseImage>::parse(INPUT).unwrap();
+        assert_eq!(solve1(cimage), LitCount(Some(35)));
+    }
+
+    fn check_rectangular<I: Image>() {
+        // The example image cropped to its top two rows, and to its left
+        // three columns.
+        let wide = INPUT.replace("\n    ##..#\n    ..#..\n    ..###", "");
+        let tall = INPUT
+            .lines()
+            .map(|line| if line.len() == 9 { &line[..7] } else { line })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut cimage = CompressedImage::<I>::parse(&wide).unwrap();
+        assert_eq!(cimage.image.window(), Window::new(5, 2));
+        cimage.decompress(2);
+        assert_eq!(cimage.image.window(), Window::new(5, 2).grow(2));
+        assert_eq!(cimage.image.lit_count(), Some(18));
+
+        let mut cimage = CompressedImage::<I>::parse(&tall).unwrap();
+        assert_eq!(cimage.image.window(), Window::new(3, 5));
+        cimage.decompress(2);
+        assert_eq!(cimage.image.lit_count(), Some(25));
+    }
+
+    fn check_lit_background<I: Image>() {
+        // Index 0 is `#` and index 511 is `.`, so the background blinks.
+        let mut algorithm = INPUT.lines().next().unwrap().to_owned();
+        algorithm.replace_range(..1, "#");
+        algorithm.replace_range(511.., ".");
+        let input = INPUT.replacen(INPUT.lines().next().unwrap(), &algorithm, 1);
+
+        let mut cimage = CompressedImage::<I>::parse(&input).unwrap();
+        cimage.decompress(1);
+        assert!(cimage.image.background());
+        assert_eq!(cimage.image.lit_count(), None);
+        cimage.decompress(1);
+        assert!(!cimage.image.background());
+        assert_eq!(cimage.image.lit_count(), Some(24));
+
+        // Pixels in a wide image can be right of `max_y` but still inside
+        // the window.
+        let wide = input.replace("\n    ##..#\n    ..#..\n    ..###", "");
+        let mut cimage = CompressedImage::<I>::parse(&wide).unwrap();
+        cimage.decompress(2);
+        assert_eq!(cimage.image.lit_count(), Some(12));
+        cimage.decompress(2);
+        assert_eq!(cimage.image.lit_count(), Some(28));
+    }
+
+    #[test]
+    fn test_rectangular() {
+        check_rectangular::<SparseImage>();
+        check_rectangular::<DenseImage>();
+    }
+
+    #[test]
+    fn test_lit_background() {
+        check_lit_background::<SparseImage>();
+        check_lit_background::<DenseImage>();
+
+        // Once every pixel is lit, it stays that way.
+        let input = INPUT.replacen(INPUT.lines().next().unwrap(), &"#".repeat(512), 1);
+        let cimage = Day20::parse(&input).unwrap();
+        assert_eq!(Day20::solve1(&cimage), LitCount(None));
+        assert_eq!(Day20::solve2(&cimage).to_string(), "infinite");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let algorithm = INPUT.lines().next().unwrap();
+        let err = CompressedImage::<DenseImage>::parse(&format!("{}\n#..#.\n#....", algorithm))
+            .err()
+            .unwrap();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (2, "expected a blank line after the algorithm")
+        );
+
+        let err =
+            CompressedImage::<DenseImage>::parse(&format!("{}\n\n#..#.\n#...\n##..#", algorithm))
+                .err()
+                .unwrap();
+        assert_eq!((err.line, err.column), (4, 5));
+        assert_eq!(err.message, "expected 5 pixels");
+
+        let err = CompressedImage::<DenseImage>::parse(&format!("{}\n\n#..#.\n#....#", algorithm))
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (4, 6, "#"));
+    }
+
+    #[test]
+    fn test_backends_agree() {
+        let mut sparse = CompressedImage::<SparseImage>::parse(INPUT).unwrap();
+        let mut dense = CompressedImage::<DenseImage>::parse(INPUT).unwrap();
+        for _ in 0..30 {
+            sparse.decompress(1);
+            dense.decompress(1);
+            assert_eq!(dense.image.window(), sparse.image.window());
+            assert_eq!(dense.image.render(), sparse.image.render());
+        }
+    }
+
+    #[test]
+    fn test_solve2() {
+        let cimage = CompressedImage::<SparseImage>::parse(INPUT).unwrap();
+        assert_eq!(solve2(cimage), LitCount(Some(3351)));
+        let cimage = CompressedImage::<DenseImage>::parse(INPUT).unwrap();
+        assert_eq!(solve2(cimage), LitCount(Some(3351)));
+    }
+}
@@ -1,173 +1,123 @@
-use std::collections::HashSet;
-
-#[derive(Clone)]
-struct CompressedImage {
-    enhancement_algorithm: Vec<bool>,
-    image: HashSet<(isize, isize)>,
-    min_x: isize,
-    min_y: isize,
-    max_x: isize,
-    max_y: isize,
-    background: bool,
+use day20::export::{self, ExportError, Format};
+use day20::{CompressedImage, Day20, Image};
+use std::env;
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process;
+
+const USAGE: &str = "usage: day20 [input]
+       day20 --export <file.pbm|file.png> [--steps <n>] [input]
+       day20 --frames <dir> [--format pbm|png] [--steps <n>] [input]";
+
+enum Output {
+    /// One image after the last step, in the format its extension names.
+    File(PathBuf, Format),
+    /// One numbered image per step in a directory.
+    Frames(PathBuf, Format),
 }
 
-impl CompressedImage {
-    fn from_str(input: &str) -> Self {
-        let mut input_iter = input.trim().lines();
-        let enhancement_algorithm: Vec<bool> = input_iter
-            .next()
-            .unwrap()
-            .chars()
-            .map(|c| c == '#')
-            .collect();
-        let mut image = HashSet::new();
-        input_iter.next(); // skip blank
-        let mut min_x = isize::MAX;
-        let mut min_y = isize::MAX;
-        let mut max_x = isize::MIN;
-        let mut max_y = isize::MIN;
-        for (y, row) in input_iter.enumerate() {
-            for (x, col) in row.trim().chars().enumerate() {
-                let x = x as isize;
-                let y = y as isize;
-                min_x = min_x.min(x);
-                min_y = min_y.min(y);
-                max_x = max_x.max(x);
-                max_y = max_y.max(y);
-                if col == '#' {
-                    image.insert((x, y));
-                }
-            }
-        }
+struct Args {
+    output: Output,
+    steps: usize,
+    input: Option<OsString>,
+}
 
-        CompressedImage {
-            enhancement_algorithm,
-            image,
-            min_x,
-            min_y,
-            max_x,
-            max_y,
-            background: false,
+fn parse_format(name: &str) -> Result<Format, String> {
+    match Format::from_extension(name) {
+        Some(format) => Ok(format),
+        None if name.eq_ignore_ascii_case("png") => {
+            Err("PNG output requires the `png` feature".to_owned())
         }
+        None => Err(format!("unknown image format: {}", name)),
     }
+}
 
-    fn lookup(&self, x: isize, y: isize) -> usize {
-        let mut output = 0;
-        for ydiff in -1..=1 {
-            for xdiff in -1..=1 {
-                let x = x + xdiff;
-                let y = y + ydiff;
-                let lit = self.image.contains(&(x, y))
-                    || (self.background
-                        && (x < self.min_x || x > self.max_y || y < self.min_y || y > self.max_y));
-                if lit {
-                    output |= 1;
-                }
-                output <<= 1;
+fn parse_args(mode: &str, args: impl Iterator<Item = OsString>) -> Result<Args, String> {
+    let mut args = args.map(|arg| {
+        arg.into_string()
+            .map_err(|arg| format!("invalid argument: {:?}", arg))
+    });
+    let path = args
+        .next()
+        .ok_or_else(|| format!("{} requires a path", mode))??;
+    let path = PathBuf::from(path);
+    let mut format = None;
+    let mut steps = 50;
+    let mut input = None;
+    while let Some(arg) = args.next() {
+        let arg = arg?;
+        match arg.as_str() {
+            "--format" if mode == "--frames" => {
+                let name = args.next().ok_or("--format requires a value")??;
+                format = Some(parse_format(&name)?);
+            }
+            "--steps" => {
+                let count = args.next().ok_or("--steps requires a value")??;
+                steps = count
+                    .parse()
+                    .map_err(|_| format!("invalid step count: {}", count))?;
             }
+            other if other.starts_with("--") => return Err(format!("unknown argument: {}", other)),
+            _ if input.is_none() => input = Some(OsString::from(arg)),
+            other => return Err(format!("unexpected argument: {}", other)),
         }
-        output >> 1
     }
 
-    fn decompress(&mut self, iterations: usize) {
-        for _ in 0..iterations {
-            let mut turn_on = Vec::new();
-            let mut turn_off = Vec::new();
-            let mut new_min_x = self.min_x;
-            let mut new_min_y = self.min_y;
-            let mut new_max_x = self.max_x;
-            let mut new_max_y = self.max_y;
-            for y in self.min_y - 1..=self.max_y + 1 {
-                for x in self.min_x - 1..=self.max_x + 1 {
-                    if self.enhancement_algorithm[self.lookup(x, y)] {
-                        turn_on.push((x, y));
-                        new_min_x = new_min_x.min(x);
-                        new_min_y = new_min_y.min(y);
-                        new_max_x = new_max_x.max(x);
-                        new_max_y = new_max_y.max(y);
-                    } else {
-                        turn_off.push((x, y));
-                    }
-                }
-            }
-            if self.background {
-                self.background = self.enhancement_algorithm[0b111111111];
-            } else {
-                self.background = self.enhancement_algorithm[0];
-            }
+    let output = if mode == "--frames" {
+        Output::Frames(path, format.unwrap_or(Format::Pbm))
+    } else {
+        let extension = path.extension().and_then(|ext| ext.to_str()).unwrap_or("");
+        let format = parse_format(extension)?;
+        Output::File(path, format)
+    };
+
+    Ok(Args {
+        output,
+        steps,
+        input,
+    })
+}
 
-            for set_on in turn_on {
-                self.image.insert(set_on);
-            }
-            for set_off in turn_off {
-                self.image.remove(&set_off);
-            }
-            self.min_x = new_min_x;
-            self.min_y = new_min_y;
-            self.max_x = new_max_x;
-            self.max_y = new_max_y;
+fn export(image: &CompressedImage, args: &Args) -> Result<(), ExportError> {
+    match &args.output {
+        Output::File(path, format) => {
+            let mut image = image.clone();
+            image.decompress(args.steps);
+            let image = image.image();
+            export::save(image, image.window(), *format, path)
         }
-    }
-
-    #[allow(dead_code)]
-    fn display(&self) {
-        for y in self.min_y - 1..self.max_y + 1 {
-            for x in self.min_x - 1..self.max_x + 1 {
-                if self.image.contains(&(x, y)) {
-                    print!("#");
-                } else {
-                    print!(".");
-                }
-            }
-            println!();
+        Output::Frames(dir, format) => {
+            let paths = export::save_frames(image, args.steps, *format, dir)?;
+            println!("wrote {} frames to {}", paths.len(), dir.display());
+            Ok(())
         }
     }
 }
 
-fn solve1(mut image: CompressedImage) -> usize {
-    image.decompress(2);
-    image.image.len()
-}
-
-fn solve2(mut image: CompressedImage) -> usize {
-    image.decompress(50);
-    image.image.len()
-}
-
 fn main() {
-    let input = CompressedImage::from_str(include_str!("input.txt").trim());
-
-    println!("part 1: {}", solve1(input.clone()));
-    println!("part 2: {}", solve2(input));
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = r#"..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#",
-
-    #..#.
-    #....
-    ##..#
-    ..#..
-    ..###"#;
-
-    #[test]
-    fn test_lookup() {
-        let cimage = CompressedImage::from_str(&INPUT);
-        assert_eq!(cimage.lookup(2, 2), 34);
-    }
+    let mut args = env::args_os().skip(1).peekable();
+    let mode = match args.peek().and_then(|arg| arg.to_str()) {
+        Some(mode @ ("--export" | "--frames")) => mode.to_owned(),
+        _ => {
+            common::main::<Day20>();
+            return;
+        }
+    };
+    args.next();
+
+    let args = match parse_args(&mode, args) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("error: {}\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
 
-    #[test]
-    fn test_solve1() {
-        let cimage = CompressedImage::from_str(&INPUT);
-        assert_eq!(solve1(cimage), 35);
-    }
+    let input = common::load_or_exit::<Day20>(args.input.clone());
+    let image = common::parse_or_exit::<Day20>(&input);
 
-    #[test]
-    fn test_solve2() {
-        let cimage = CompressedImage::from_str(&INPUT);
-        assert_eq!(solve2(cimage), 3351);
+    if let Err(err) = export(&image, &args) {
+        eprintln!("error: {}", err);
+        process::exit(1);
     }
 }
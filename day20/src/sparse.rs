@@ -0,0 +1,67 @@
+use crate::{Image, Window};
+use grid::SparseGrid;
+
+/// An image storing its lit pixels in a hash map, so memory is proportional
+/// to the number of lit pixels rather than the size of the window.
+#[derive(Clone, Debug)]
+pub struct SparseImage {
+    window: Window,
+    pixels: SparseGrid<()>,
+    background: bool,
+}
+
+impl Image for SparseImage {
+    fn new(window: Window, background: bool) -> Self {
+        Self {
+            window,
+            pixels: SparseGrid::new(),
+            background,
+        }
+    }
+
+    fn window(&self) -> Window {
+        self.window
+    }
+
+    fn background(&self) -> bool {
+        self.background
+    }
+
+    fn get(&self, x: isize, y: isize) -> bool {
+        if self.window.contains(x, y) {
+            self.pixels.contains(x, y)
+        } else {
+            self.background
+        }
+    }
+
+    fn set(&mut self, x: isize, y: isize, lit: bool) {
+        assert!(
+            self.window.contains(x, y),
+            "({}, {}) is outside the window",
+            x,
+            y
+        );
+        if lit {
+            self.pixels.insert(x, y, ());
+        } else {
+            self.pixels.remove(x, y);
+        }
+    }
+
+    fn lit_count(&self) -> Option<usize> {
+        (!self.background).then(|| self.pixels.len())
+    }
+
+    fn enhance(&mut self, algorithm: &[bool]) {
+        let background = algorithm[if self.background { 0b111111111 } else { 0 }];
+        let mut next = SparseImage::new(self.window.grow(1), background);
+        for (x, y) in next.window.positions() {
+            if algorithm[self.lookup(x, y)] {
+                next.pixels.insert(x, y, ());
+            }
+        }
+
+        *self = next;
+    }
+}
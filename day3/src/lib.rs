@@ -0,0 +1,194 @@
+use common::{content_lines, ParseError, Solution};
+use std::collections::HashSet;
+
+fn solve1(input: &[&str]) -> i32 {
+    let mut gamma = 0;
+    let mut epsilon = 0;
+
+    let number_len = input[0].len();
+
+    for x in (0..number_len).rev() {
+        let mut zeros = 0;
+        let mut ones = 0;
+
+        for val in input.iter() {
+            match val.as_bytes()[x] as char {
+                '0' => zeros += 1,
+                '1' => ones += 1,
+                _ => panic!("bad binary: {}", val),
+            };
+        }
+
+        if ones > zeros {
+            gamma |= 1 << (number_len - x - 1);
+        } else {
+            epsilon |= 1 << (number_len - x - 1);
+        }
+    }
+
+    gamma * epsilon
+}
+
+fn solve2(input: &[&str]) -> i32 {
+    let mut oxygen_set: HashSet<_> = HashSet::from_iter(input.iter());
+    let mut scrubber_set: HashSet<_> = HashSet::from_iter(input.iter());
+
+    let number_len = input[0].len();
+
+    for x in 0..number_len {
+        if oxygen_set.len() > 1 {
+            let mut zeros = 0;
+            let mut ones = 0;
+
+            for candidate in oxygen_set.iter() {
+                match candidate.as_bytes()[x] as char {
+                    '0' => zeros += 1,
+                    '1' => ones += 1,
+                    _ => panic!("bad binary: {}", candidate),
+                };
+            }
+
+            let mut remove_set = HashSet::new();
+
+            for candidate in oxygen_set.iter() {
+                match candidate.as_bytes()[x] as char {
+                    '0' => {
+                        if ones >= zeros {
+                            remove_set.insert(*candidate);
+                        }
+                    }
+                    '1' => {
+                        if zeros > ones {
+                            remove_set.insert(*candidate);
+                        }
+                    }
+                    _ => panic!("bad binary: {}", candidate),
+                };
+            }
+
+            for remove in remove_set {
+                oxygen_set.remove(remove);
+            }
+        }
+
+        if scrubber_set.len() > 1 {
+            let mut zeros = 0;
+            let mut ones = 0;
+            for candidate in scrubber_set.iter() {
+                match candidate.as_bytes()[x] as char {
+                    '0' => zeros += 1,
+                    '1' => ones += 1,
+                    _ => panic!("bad binary: {}", candidate),
+                };
+            }
+
+            let mut remove_set = HashSet::new();
+
+            for candidate in scrubber_set.iter() {
+                match candidate.as_bytes()[x] as char {
+                    '0' => {
+                        if zeros > ones {
+                            remove_set.insert(*candidate);
+                        }
+                    }
+                    '1' => {
+                        if ones >= zeros {
+                            remove_set.insert(*candidate);
+                        }
+                    }
+                    _ => panic!("bad binary: {}", candidate),
+                };
+            }
+
+            for remove in remove_set {
+                scrubber_set.remove(remove);
+            }
+        }
+    }
+
+    let oxygen = i32::from_str_radix(oxygen_set.iter().next().unwrap(), 2).unwrap();
+    let scrubber = i32::from_str_radix(scrubber_set.iter().next().unwrap(), 2).unwrap();
+
+    oxygen * scrubber
+}
+
+fn parse_input(input: &str) -> Result<Vec<&str>, ParseError> {
+    let mut parsed: Vec<&str> = Vec::new();
+    for (idx, line) in content_lines(input) {
+        if line.is_empty() {
+            return Err(ParseError::at_end(idx, line, "expected binary digits"));
+        }
+        if let Some(bad) = line.split(['0', '1']).find(|s| !s.is_empty()) {
+            return Err(ParseError::at(idx, line, bad, "expected binary digits"));
+        }
+        if let Some(first) = parsed.first() {
+            if line.len() != first.len() {
+                return Err(ParseError::at(
+                    idx,
+                    line,
+                    line,
+                    format!("expected {} digits", first.len()),
+                ));
+            }
+        }
+        parsed.push(line);
+    }
+    if parsed.is_empty() {
+        return Err(ParseError::at_end(0, "", "expected binary numbers"));
+    }
+
+    Ok(parsed)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<&'a str>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        parse_input(input)
+    }
+
+    fn solve1(input: &Vec<&str>) -> i32 {
+        solve1(input)
+    }
+
+    fn solve2(input: &Vec<&str>) -> i32 {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "00100", "11110", "10110", "10111", "10101", "01111", "00111", "11100", "10000", "11001",
+        "00010", "01010",
+    ];
+
+    #[test]
+    fn test_solve1() {
+        assert_eq!(solve1(INPUT), 198);
+    }
+
+    #[test]
+    fn test_solve2() {
+        assert_eq!(solve2(INPUT), 230);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("").unwrap_err();
+        assert_eq!(err.message, "expected binary numbers");
+        let err = parse_input("\n101\n\n101").unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (3, "expected binary digits")
+        );
+    }
+}
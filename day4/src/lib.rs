@@ -0,0 +1,281 @@
+use common::{content_lines, parse_number, ParseError, Solution};
+use std::collections::HashSet;
+
+#[allow(clippy::needless_range_loop)]
+fn solve1(numbers: &[i32], boards: &[Vec<Vec<i32>>]) -> i32 {
+    let mut board_tracker = vec![vec![vec![false; 5]; 5]; boards.len()];
+
+    for num in numbers {
+        for (board_id, board) in boards.iter().enumerate() {
+            for (row_id, row) in board.iter().enumerate() {
+                for (col_id, col) in row.iter().enumerate() {
+                    if col == num {
+                        board_tracker[board_id][row_id][col_id] = true;
+
+                        // evaluate if bingo
+                        let mut bingo = false;
+                        // scan rows
+                        for tracker_row in &board_tracker[board_id] {
+                            let mut found_bingo = true;
+                            for col in tracker_row.iter() {
+                                if !col {
+                                    found_bingo = false;
+                                    break;
+                                }
+                            }
+                            if found_bingo {
+                                bingo = true;
+                                break;
+                            }
+                        }
+
+                        if !bingo {
+                            // scan cols
+                            for x in 0..5 {
+                                let mut found_bingo = true;
+                                for y in 0..5 {
+                                    if !board_tracker[board_id][y][x] {
+                                        found_bingo = false;
+                                        break;
+                                    }
+                                }
+                                if found_bingo {
+                                    bingo = true;
+                                    break;
+                                }
+                            }
+                        }
+
+                        if bingo {
+                            // get score
+                            let wining_board = &boards[board_id];
+                            let wining_tracker = &board_tracker[board_id];
+                            let mut score = 0;
+
+                            for (row, tracker_row) in wining_board.iter().zip(wining_tracker.iter())
+                            {
+                                for (col, tracker_col) in row.iter().zip(tracker_row.iter()) {
+                                    if !tracker_col {
+                                        score += col;
+                                    }
+                                }
+                            }
+
+                            score *= num;
+
+                            return score;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    0
+}
+
+#[allow(clippy::needless_range_loop)]
+fn solve2(numbers: &[i32], boards: &[Vec<Vec<i32>>]) -> i32 {
+    let mut boards_left: HashSet<_> = HashSet::from_iter(0..boards.len());
+    let mut board_tracker = vec![vec![vec![false; 5]; 5]; boards.len()];
+
+    for num in numbers {
+        for (board_id, board) in boards.iter().enumerate() {
+            if !boards_left.contains(&board_id) {
+                continue;
+            }
+            for (row_id, row) in board.iter().enumerate() {
+                for (col_id, col) in row.iter().enumerate() {
+                    if col == num {
+                        board_tracker[board_id][row_id][col_id] = true;
+
+                        // evaluate if bingo
+                        let mut bingo = false;
+                        // scan rows
+                        for tracker_row in &board_tracker[board_id] {
+                            let mut found_bingo = true;
+                            for col in tracker_row.iter() {
+                                if !col {
+                                    found_bingo = false;
+                                    break;
+                                }
+                            }
+                            if found_bingo {
+                                bingo = true;
+                                break;
+                            }
+                        }
+
+                        if !bingo {
+                            // scan cols
+                            for x in 0..5 {
+                                let mut found_bingo = true;
+                                for y in 0..5 {
+                                    if !board_tracker[board_id][y][x] {
+                                        found_bingo = false;
+                                        break;
+                                    }
+                                }
+                                if found_bingo {
+                                    bingo = true;
+                                    break;
+                                }
+                            }
+                        }
+
+                        if bingo && boards_left.len() == 1 {
+                            // get score
+                            let wining_board = &boards[board_id];
+                            let wining_tracker = &board_tracker[board_id];
+                            let mut score = 0;
+
+                            for (row, tracker_row) in wining_board.iter().zip(wining_tracker.iter())
+                            {
+                                for (col, tracker_col) in row.iter().zip(tracker_row.iter()) {
+                                    if !tracker_col {
+                                        score += col;
+                                    }
+                                }
+                            }
+
+                            score *= num;
+
+                            return score;
+                        } else if bingo {
+                            boards_left.remove(&board_id);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    0
+}
+
+type Board = Vec<Vec<i32>>;
+
+fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<Board>), ParseError> {
+    let mut input = content_lines(input);
+    let (idx, line) = input
+        .next()
+        .ok_or_else(|| ParseError::at_end(0, "", "expected the drawn numbers"))?;
+    let numbers = line
+        .split(',')
+        .map(|txt_num| parse_number(idx, line, txt_num))
+        .collect::<Result<Vec<i32>, _>>()?;
+
+    // parse boards
+    let mut boards = Vec::new();
+    while let Some((idx, line)) = input.next() {
+        if !line.trim().is_empty() {
+            return Err(ParseError::at(
+                idx,
+                line,
+                line,
+                "expected a blank line before each board",
+            ));
+        }
+
+        let mut last_idx = idx;
+        let mut board = Vec::new();
+        for _ in 0..5 {
+            let (idx, line) = input
+                .next()
+                .ok_or_else(|| ParseError::at_end(last_idx + 1, "", "expected a board row"))?;
+            let row = line
+                .split_whitespace()
+                .map(|val| parse_number(idx, line, val))
+                .collect::<Result<Vec<i32>, _>>()?;
+            if row.len() != 5 {
+                return Err(ParseError::at(idx, line, line, "expected 5 numbers"));
+            }
+            board.push(row);
+            last_idx = idx;
+        }
+        boards.push(board);
+    }
+
+    Ok((numbers, boards))
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = (Vec<i32>, Vec<Board>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<(Vec<i32>, Vec<Board>), ParseError> {
+        parse_input(input)
+    }
+
+    fn solve1((numbers, boards): &(Vec<i32>, Vec<Board>)) -> i32 {
+        solve1(numbers, boards)
+    }
+
+    fn solve2((numbers, boards): &(Vec<i32>, Vec<Board>)) -> i32 {
+        solve2(numbers, boards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NUMBERS: &[i32] = &[
+        7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24, 10, 16, 13, 6, 15, 25, 12, 22, 18, 20, 8, 19, 3,
+        26, 1,
+    ];
+    const BOARDS: &[&[&[i32]]] = &[
+        &[
+            &[22, 13, 17, 11, 0],
+            &[8, 2, 23, 4, 24],
+            &[21, 9, 14, 16, 7],
+            &[6, 10, 3, 18, 5],
+            &[1, 12, 20, 15, 19],
+        ],
+        &[
+            &[3, 15, 0, 2, 22],
+            &[9, 18, 13, 17, 5],
+            &[19, 8, 7, 25, 23],
+            &[20, 11, 10, 24, 4],
+            &[14, 21, 16, 12, 6],
+        ],
+        &[
+            &[14, 21, 17, 24, 4],
+            &[10, 16, 15, 9, 19],
+            &[18, 8, 23, 26, 20],
+            &[22, 11, 13, 6, 5],
+            &[2, 0, 12, 3, 7],
+        ],
+    ];
+
+    fn boards_to_vec() -> Vec<Vec<Vec<i32>>> {
+        let mut boards = Vec::new();
+        for board_data in BOARDS.iter() {
+            let mut board = Vec::new();
+            for row in board_data.iter() {
+                board.push(row.to_vec());
+            }
+
+            boards.push(board);
+        }
+
+        boards
+    }
+
+    #[test]
+    fn test_solve1() {
+        let boards = boards_to_vec();
+        assert_eq!(solve1(NUMBERS, &boards), 4512);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let boards = boards_to_vec();
+        assert_eq!(solve2(NUMBERS, &boards), 1924);
+    }
+}
@@ -0,0 +1,212 @@
+use common::{parse_number, ParseError, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct Line {
+    start_x: i32,
+    start_y: i32,
+    end_x: i32,
+    end_y: i32,
+}
+
+fn parse_input(input: &[&str]) -> Result<Vec<Line>, ParseError> {
+    let mut lines = Vec::new();
+
+    let numbered = input.iter().enumerate();
+    for (idx, line) in numbered.skip_while(|(_, line)| line.trim().is_empty()) {
+        let bad_line = || ParseError::at(idx, line, line, "expected `x1,y1 -> x2,y2`");
+        let (left, right) = line.split_once(" -> ").ok_or_else(bad_line)?;
+        let (start_x, start_y) = left.split_once(',').ok_or_else(bad_line)?;
+        let (end_x, end_y) = right.split_once(',').ok_or_else(bad_line)?;
+        let start_x: i32 = parse_number(idx, line, start_x)?;
+        let start_y: i32 = parse_number(idx, line, start_y)?;
+        let end_x = parse_number(idx, line, end_x)?;
+        let end_y = parse_number(idx, line, end_y)?;
+        if start_x != end_x
+            && start_y != end_y
+            && (start_x - end_x).abs() != (start_y - end_y).abs()
+        {
+            return Err(ParseError::at(
+                idx,
+                line,
+                line,
+                "expected a horizontal, vertical or 45 degree line",
+            ));
+        }
+
+        lines.push(Line {
+            start_x,
+            start_y,
+            end_x,
+            end_y,
+        });
+    }
+
+    Ok(lines)
+}
+
+fn solve1(lines: &[Line]) -> i32 {
+    let mut grid: HashMap<(i32, i32), i32> = HashMap::new();
+
+    for line in lines {
+        // only horizontal or vertical lines
+        if line.start_x == line.end_x || line.start_y == line.end_y {
+            let mut x = line.start_x;
+            let x_step = if line.start_x > line.end_x { -1 } else { 1 };
+
+            let mut y = line.start_y;
+            let y_step = if line.start_y > line.end_y { -1 } else { 1 };
+
+            loop {
+                loop {
+                    let point = grid.entry((x, y)).or_default();
+                    *point += 1;
+
+                    if y == line.end_y {
+                        break;
+                    }
+                    y += y_step;
+                }
+
+                if x == line.end_x {
+                    break;
+                }
+                x += x_step;
+            }
+        }
+    }
+
+    // get score
+    let mut score = 0;
+    for val in grid.values() {
+        if *val >= 2 {
+            score += 1;
+        }
+    }
+
+    score
+}
+
+fn solve2(lines: &[Line]) -> i32 {
+    let mut grid: HashMap<(i32, i32), i32> = HashMap::new();
+
+    for line in lines {
+        let mut x = line.start_x;
+        let x_step = if line.start_x > line.end_x { -1 } else { 1 };
+
+        let mut y = line.start_y;
+        let y_step = if line.start_y > line.end_y { -1 } else { 1 };
+
+        // only horizontal or vertical lines
+        if line.start_x == line.end_x || line.start_y == line.end_y {
+            loop {
+                loop {
+                    let point = grid.entry((x, y)).or_default();
+                    *point += 1;
+
+                    if y == line.end_y {
+                        break;
+                    }
+                    y += y_step;
+                }
+
+                if x == line.end_x {
+                    break;
+                }
+                x += x_step;
+            }
+        } else {
+            // diagonal
+            loop {
+                let point = grid.entry((x, y)).or_default();
+                *point += 1;
+
+                if x == line.end_x && y == line.end_y {
+                    break;
+                }
+                x += x_step;
+                y += y_step;
+            }
+        }
+    }
+
+    // get score
+    let mut score = 0;
+    for val in grid.values() {
+        if *val >= 2 {
+            score += 1;
+        }
+    }
+
+    score
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Vec<Line>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+        let input: Vec<&str> = input.trim_end().lines().collect();
+        parse_input(&input)
+    }
+
+    fn solve1(input: &Vec<Line>) -> i32 {
+        solve1(input)
+    }
+
+    fn solve2(input: &Vec<Line>) -> i32 {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "0,9 -> 5,9",
+        "8,0 -> 0,8",
+        "9,4 -> 3,4",
+        "2,2 -> 2,1",
+        "7,0 -> 7,4",
+        "6,4 -> 2,0",
+        "0,9 -> 2,9",
+        "3,4 -> 1,4",
+        "0,0 -> 8,8",
+        "5,5 -> 8,2",
+    ];
+
+    #[test]
+    fn test_solve1() {
+        let parsed = parse_input(INPUT).unwrap();
+        assert_eq!(solve1(&parsed), 5);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let parsed = parse_input(INPUT).unwrap();
+        assert_eq!(solve2(&parsed), 12);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input(&["0,9 -> 5,9", "8,0 -> 0;8"]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = parse_input(&["0,9 -> 5,y"]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 10));
+        assert_eq!(err.text, "y");
+
+        let err = parse_input(&["0,9 -> 5,9", "0,0 -> 1,5"]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(
+            err.message,
+            "expected a horizontal, vertical or 45 degree line"
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use common::{parse_number, single_line, ParseError, Solution};
+
+fn solve1(input: &[i32]) -> i32 {
+    let min_pos: i32 = *input.iter().min().unwrap();
+    let max_pos: i32 = *input.iter().max().unwrap();
+    let mut min_cost = i32::MAX;
+
+    for align_pos in min_pos..=max_pos {
+        let mut sum = 0;
+        for crab in input {
+            let diff = *crab - align_pos;
+            sum += diff.abs();
+        }
+
+        if sum < min_cost {
+            min_cost = sum;
+        }
+    }
+
+    min_cost
+}
+
+fn solve2(input: &[i32]) -> i32 {
+    let min_pos: i32 = *input.iter().min().unwrap();
+    let max_pos: i32 = *input.iter().max().unwrap();
+    let mut min_cost = i32::MAX;
+    let mut diffs = vec![0; (max_pos + 1) as usize];
+
+    for align_pos in min_pos..=max_pos {
+        let mut sum = 0;
+        for crab in input {
+            let diff = (*crab - align_pos).abs();
+            sum += if diffs[diff as usize] != 0 {
+                diffs[diff as usize]
+            } else {
+                let mut precalc = 0;
+                for x in 1..=diff {
+                    precalc += x;
+                }
+                diffs[diff as usize] = precalc;
+                precalc
+            };
+        }
+
+        if sum < min_cost {
+            min_cost = sum;
+        }
+    }
+
+    min_cost
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        let (idx, line) = single_line(input)?;
+        line.split(',')
+            .map(|l| parse_number(idx, line, l))
+            .collect()
+    }
+
+    fn solve1(input: &Vec<i32>) -> i32 {
+        solve1(input)
+    }
+
+    fn solve2(input: &Vec<i32>) -> i32 {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[i32] = &[16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+
+    #[test]
+    fn test_solve1() {
+        assert_eq!(solve1(INPUT), 37);
+    }
+
+    #[test]
+    fn test_solve2() {
+        assert_eq!(solve2(INPUT), 168);
+    }
+}
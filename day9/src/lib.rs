@@ -0,0 +1,100 @@
+use common::{ParseError, Solution};
+use grid::Grid;
+
+fn solve1(input: &Grid<i32>) -> i32 {
+    let mut sum = 0;
+    for (x, y) in input.positions() {
+        let val = input[(x, y)];
+
+        if input.neighbours4(x, y).any(|pos| input[pos] <= val) {
+            continue;
+        }
+
+        sum += val + 1;
+    }
+
+    sum
+}
+
+fn rec_fill_basin(
+    cur_pos: (usize, usize),
+    basin_id: i32,
+    input: &Grid<i32>,
+    basin_tracker: &mut Grid<i32>,
+) {
+    basin_tracker[cur_pos] = basin_id;
+
+    for next_pos in input.neighbours4(cur_pos.0, cur_pos.1) {
+        if input[next_pos] != 9 && basin_tracker[next_pos] == 0 {
+            rec_fill_basin(next_pos, basin_id, input, basin_tracker);
+        }
+    }
+}
+
+fn solve2(input: &Grid<i32>) -> i32 {
+    let mut basin_tracker: Grid<i32> = Grid::new(input.width(), input.height(), 0);
+    let mut basin_id = 1;
+    for pos in input.positions() {
+        if input[pos] == 9 || basin_tracker[pos] > 0 {
+            continue;
+        }
+        rec_fill_basin(pos, basin_id, input, &mut basin_tracker);
+        basin_id += 1;
+    }
+
+    let mut basins = Vec::new();
+    for x in 1..basin_id {
+        basins.push(basin_tracker.iter().filter(|r| **r == x).count());
+    }
+
+    basins.sort_unstable_by(|a, b| b.cmp(a));
+
+    basins.iter().take(3).fold(1, |a, x| a * *x as i32)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Grid<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Grid<i32>, ParseError> {
+        Ok(Grid::parse_digits(input)?.map(|d| *d as i32))
+    }
+
+    fn solve1(input: &Grid<i32>) -> i32 {
+        solve1(input)
+    }
+
+    fn solve2(input: &Grid<i32>) -> i32 {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&[i32]] = &[
+        &[2, 1, 9, 9, 9, 4, 3, 2, 1, 0],
+        &[3, 9, 8, 7, 8, 9, 4, 9, 2, 1],
+        &[9, 8, 5, 6, 7, 8, 9, 8, 9, 2],
+        &[8, 7, 6, 7, 8, 9, 6, 7, 8, 9],
+        &[9, 8, 9, 9, 9, 6, 5, 6, 7, 8],
+    ];
+
+    #[test]
+    fn test_solve1() {
+        let input = Grid::from_rows(INPUT.iter().map(|row| row.to_vec()).collect());
+        assert_eq!(solve1(&input), 15);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input = Grid::from_rows(INPUT.iter().map(|row| row.to_vec()).collect());
+        assert_eq!(solve2(&input), 1134);
+    }
+}
@@ -0,0 +1,227 @@
+use common::{parse_digit_rows, ParseError};
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid stored row-major in a single `Vec`, indexed by `(x, y)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Reset every cell to `value`.
+    pub fn fill(&mut self, value: T) {
+        self.cells.fill(value);
+    }
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from equal-length rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows have different lengths.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "grid rows must all be the same length"
+        );
+
+        Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of cells, `width * height`.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.contains(x, y) {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cell values in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// In-bounds positions left, up, right and down of `(x, y)`.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(x, y, &[(-1, 0), (0, -1), (1, 0), (0, 1)])
+    }
+
+    /// In-bounds positions of the eight cells surrounding `(x, y)`, row-major.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(
+            x,
+            y,
+            &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        )
+    }
+
+    fn offsets(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let x = x.checked_add_signed(*dx)?;
+            let y = y.checked_add_signed(*dy)?;
+            (x < width && y < height).then_some((x, y))
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Render one character per cell, one line per row.
+    pub fn render_with(&self, f: impl Fn(&T) -> char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            output.extend(row.iter().map(&f));
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+impl Grid<u32> {
+    /// Parse rows of single digits.
+    pub fn parse_digits(input: &str) -> Result<Self, ParseError> {
+        Ok(Self::from_rows(parse_digit_rows(input)?))
+    }
+}
+
+impl Grid<bool> {
+    /// Parse rows of `#` (true) and `.` (false).
+    pub fn parse_pixels(input: &str) -> Result<Self, ParseError> {
+        Ok(Self::from_rows(crate::parse_pixel_rows(input)?))
+    }
+
+    /// Render as `#` and `.`, the inverse of [`Grid::parse_pixels`].
+    pub fn render(&self) -> String {
+        self.render_with(|&lit| if lit { '#' } else { '.' })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(self.contains(x, y), "({}, {}) is out of bounds", x, y);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(self.contains(x, y), "({}, {}) is out of bounds", x, y);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 2, 0);
+        let around_corner: Vec<_> = grid.neighbours4(0, 0).collect();
+        assert_eq!(around_corner, vec![(1, 0), (0, 1)]);
+
+        let around_edge: Vec<_> = grid.neighbours8(1, 1).collect();
+        assert_eq!(around_edge, vec![(0, 0), (1, 0), (2, 0), (0, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn test_parse_render() {
+        let grid = Grid::parse_pixels("#..\n.#.\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid[(1, 1)] && !grid[(2, 1)]);
+        assert_eq!(grid.render(), "#..\n.#.\n");
+
+        let digits = Grid::parse_digits("123\n456").unwrap();
+        assert_eq!(digits[(2, 1)], 6);
+        assert_eq!(
+            digits.render_with(|d| char::from_digit(*d, 10).unwrap()),
+            "123\n456\n"
+        );
+
+        let err = Grid::parse_pixels("#..\n.x.").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+}
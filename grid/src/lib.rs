@@ -0,0 +1,32 @@
+mod dense;
+mod sparse;
+
+pub use dense::Grid;
+pub use sparse::SparseGrid;
+
+use common::ParseError;
+
+/// Parse rows of `#` (true) and `.` (false), ignoring surrounding whitespace
+/// on each line.
+fn parse_pixel_rows(input: &str) -> Result<Vec<Vec<bool>>, ParseError> {
+    let mut rows: Vec<Vec<bool>> = Vec::new();
+    for (idx, line) in input.lines().enumerate() {
+        let row = line.trim();
+        if let Some(bad) = row.split(['#', '.']).find(|s| !s.is_empty()) {
+            return Err(ParseError::at(idx, line, bad, "expected `#` or `.`"));
+        }
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(ParseError::at(
+                    idx,
+                    line,
+                    row,
+                    format!("expected {} pixels", first.len()),
+                ));
+            }
+        }
+        rows.push(row.chars().map(|c| c == '#').collect());
+    }
+
+    Ok(rows)
+}
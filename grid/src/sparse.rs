@@ -0,0 +1,170 @@
+use common::ParseError;
+use std::collections::HashMap;
+
+const OFFSETS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (1, 0), (0, 1)];
+const OFFSETS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// An unbounded grid that only stores occupied cells, indexed by `(x, y)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(isize, isize), T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of occupied cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        self.cells.contains_key(&(x, y))
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        self.cells.get(&(x, y))
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        self.cells.get_mut(&(x, y))
+    }
+
+    pub fn insert(&mut self, x: isize, y: isize, value: T) -> Option<T> {
+        self.cells.insert((x, y), value)
+    }
+
+    pub fn remove(&mut self, x: isize, y: isize) -> Option<T> {
+        self.cells.remove(&(x, y))
+    }
+
+    /// Occupied positions, in no particular order.
+    pub fn positions(&self) -> impl Iterator<Item = (isize, isize)> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((isize, isize), &T)> {
+        self.cells.iter().map(|(pos, value)| (*pos, value))
+    }
+
+    /// Inclusive `(min, max)` corners of the occupied cells.
+    pub fn bounds(&self) -> Option<((isize, isize), (isize, isize))> {
+        let mut positions = self.positions();
+        let first = positions.next()?;
+        Some(positions.fold((first, first), |(min, max), (x, y)| {
+            ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+        }))
+    }
+
+    /// Positions left, up, right and down of `(x, y)`.
+    pub fn neighbours4(x: isize, y: isize) -> impl Iterator<Item = (isize, isize)> {
+        OFFSETS4.iter().map(move |(dx, dy)| (x + dx, y + dy))
+    }
+
+    /// Positions of the eight cells surrounding `(x, y)`, row-major.
+    pub fn neighbours8(x: isize, y: isize) -> impl Iterator<Item = (isize, isize)> {
+        OFFSETS8.iter().map(move |(dx, dy)| (x + dx, y + dy))
+    }
+
+    /// Render one character per cell across the occupied bounds.
+    pub fn render_with(&self, f: impl Fn(Option<&T>) -> char) -> String {
+        match self.bounds() {
+            Some((min, _)) => self.render_from_with(min, f),
+            None => String::new(),
+        }
+    }
+
+    /// Render one character per cell from `origin` to the bottom right
+    /// corner of the occupied cells.
+    pub fn render_from_with(
+        &self,
+        (min_x, min_y): (isize, isize),
+        f: impl Fn(Option<&T>) -> char,
+    ) -> String {
+        let mut output = String::new();
+        if let Some((_, (max_x, max_y))) = self.bounds() {
+            for y in min_y..=max_y {
+                output.extend((min_x..=max_x).map(|x| f(self.get(x, y))));
+                output.push('\n');
+            }
+        }
+
+        output
+    }
+
+    /// Render occupied cells as `#` and empty ones as `.`.
+    pub fn render(&self) -> String {
+        self.render_with(|cell| if cell.is_some() { '#' } else { '.' })
+    }
+
+    /// Render occupied cells as `#` and empty ones as `.`, from `origin`.
+    pub fn render_from(&self, origin: (isize, isize)) -> String {
+        self.render_from_with(origin, |cell| if cell.is_some() { '#' } else { '.' })
+    }
+}
+
+impl SparseGrid<()> {
+    /// Parse rows of `#` and `.`, storing the `#` cells with the top left at
+    /// `(0, 0)`.
+    pub fn parse_pixels(input: &str) -> Result<Self, ParseError> {
+        let mut grid = Self::new();
+        for (y, row) in crate::parse_pixel_rows(input)?.into_iter().enumerate() {
+            for (x, lit) in row.into_iter().enumerate() {
+                if lit {
+                    grid.insert(x as isize, y as isize, ());
+                }
+            }
+        }
+
+        Ok(grid)
+    }
+}
+
+impl<T> FromIterator<((isize, isize), T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = ((isize, isize), T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds_render() {
+        let mut grid = SparseGrid::parse_pixels(".#.\n..#").unwrap();
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bounds(), Some(((1, 0), (2, 1))));
+        assert_eq!(grid.render(), "#.\n.#\n");
+        assert_eq!(grid.render_from((0, 0)), ".#.\n..#\n");
+
+        grid.insert(-1, 2, ());
+        assert_eq!(grid.bounds(), Some(((-1, 0), (2, 2))));
+        assert_eq!(grid.render(), "..#.\n...#\n#...\n");
+    }
+}